@@ -1,4 +1,4 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{Ident, Item};
 
@@ -9,8 +9,10 @@ pub struct AssetAPIFields {
     pub transaction_payment: Ident,
     pub balance: Ident,
     pub call: Ident,
-    pub oracle_key: Ident,
-    pub oracle: Ident,
+    pub oracle_key: Option<Ident>,
+    pub oracle: Option<Ident>,
+    pub asset_conversion: Option<Ident>,
+    pub asset_fees: Ident,
 }
 
 impl TryFrom<&[Item]> for AssetAPIFields {
@@ -21,6 +23,8 @@ impl TryFrom<&[Item]> for AssetAPIFields {
         let mut balance = None;
         let mut oracle_key = None;
         let mut oracle = None;
+        let mut asset_conversion = None;
+        let mut asset_fees = None;
 
         for item in value {
             if let Item::Type(ty) = item {
@@ -34,6 +38,10 @@ impl TryFrom<&[Item]> for AssetAPIFields {
                     oracle_key = Some(fetch_ident(&ty.ty))
                 } else if ty.ident == "Oracle" {
                     oracle = Some(fetch_ident(&ty.ty))
+                } else if ty.ident == "AssetConversion" {
+                    asset_conversion = Some(fetch_ident(&ty.ty))
+                } else if ty.ident == "AssetFees" {
+                    asset_fees = Some(fetch_ident(&ty.ty))
                 }
             }
         }
@@ -42,14 +50,18 @@ impl TryFrom<&[Item]> for AssetAPIFields {
             transaction_payment.ok_or("`type TransactionPayment` not specified, but required")?;
         let balance = balance.ok_or("`type Balance` not specified, but required")?;
         let call = call.ok_or("`type RuntimeCall` not specified, but required")?;
-        let oracle_key = oracle_key.ok_or("`type OracleKey` not specified, but required")?;
-        let oracle = oracle.ok_or("`type Oracle` not specified, but required")?;
+        let asset_fees = asset_fees.unwrap_or_else(|| Ident::new("AssetFees", Span::call_site()));
+        if oracle.is_some() && oracle_key.is_none() {
+            return Err("`type OracleKey` not specified, but required with `type Oracle`");
+        }
         Ok(AssetAPIFields {
             transaction_payment,
             balance,
             call,
             oracle_key,
-            oracle
+            oracle,
+            asset_conversion,
+            asset_fees,
         })
     }
 }
@@ -60,10 +72,12 @@ pub fn assets_apis(
     transaction_payment: &Ident,
     balance: &Ident,
     call: &Ident,
-    oracle_key: &Ident,
-    oracle: &Ident
+    oracle_key: &Option<Ident>,
+    oracle: &Option<Ident>,
+    asset_conversion: &Option<Ident>,
+    asset_fees: &Ident,
 ) -> TokenStream {
-    quote! {
+    let mut res = quote! {
         impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<#block, #balance>
             for #runtime
         {
@@ -114,19 +128,81 @@ pub fn assets_apis(
             }
         }
 
-        impl orml_oracle_runtime_api::OracleApi<
+        impl openzeppelin_pallet_abstractions::assets::AssetFeeApi<
             #block,
-            (),
-            #oracle_key,
-            orml_oracle::TimestampedValue<sp_runtime::FixedU128, u64>,
-        > for Runtime {
-            fn get_value(_: (), key: #oracle_key) -> Option<orml_oracle::TimestampedValue<sp_runtime::FixedU128, u64>> {
-                #oracle::get(&key)
+            <#asset_fees as openzeppelin_pallet_abstractions::assets::FeeAssets>::AssetId,
+            #balance,
+        > for #runtime {
+            fn query_fee_in_asset(
+                uxt: <#block as sp_runtime::traits::Block>::Extrinsic,
+                len: u32,
+                asset: <#asset_fees as openzeppelin_pallet_abstractions::assets::FeeAssets>::AssetId,
+            ) -> Option<#balance> {
+                let native_fee = #transaction_payment::query_fee_details(uxt, len).final_fee();
+                <#asset_fees as openzeppelin_pallet_abstractions::assets::FeeAssets>::quote_fee(native_fee, asset)
             }
 
-            fn get_all_values(_: ()) -> sp_std::prelude::Vec<(#oracle_key, Option<orml_oracle::TimestampedValue<sp_runtime::FixedU128, u64>>)> {
-                #oracle::get_all_values()
+            fn supported_fee_assets() -> sp_std::prelude::Vec<
+                <#asset_fees as openzeppelin_pallet_abstractions::assets::FeeAssets>::AssetId,
+            > {
+                <#asset_fees as openzeppelin_pallet_abstractions::assets::FeeAssets>::supported_fee_assets()
             }
         }
+    };
+
+    if let (Some(oracle), Some(oracle_key)) = (oracle, oracle_key) {
+        res.extend(quote! {
+            impl orml_oracle_runtime_api::OracleApi<
+                #block,
+                (),
+                #oracle_key,
+                orml_oracle::TimestampedValue<sp_runtime::FixedU128, u64>,
+            > for Runtime {
+                fn get_value(_: (), key: #oracle_key) -> Option<orml_oracle::TimestampedValue<sp_runtime::FixedU128, u64>> {
+                    #oracle::get(&key)
+                }
+
+                fn get_all_values(_: ()) -> sp_std::prelude::Vec<(#oracle_key, Option<orml_oracle::TimestampedValue<sp_runtime::FixedU128, u64>>)> {
+                    #oracle::get_all_values()
+                }
+            }
+        });
+    }
+
+    if let Some(asset_conversion) = asset_conversion {
+        res.extend(quote! {
+            impl pallet_asset_conversion::AssetConversionApi<
+                #block,
+                #balance,
+                <#runtime as pallet_asset_conversion::Config>::AssetKind,
+            > for #runtime {
+                fn quote_price_exact_tokens_for_tokens(
+                    asset1: <#runtime as pallet_asset_conversion::Config>::AssetKind,
+                    asset2: <#runtime as pallet_asset_conversion::Config>::AssetKind,
+                    amount: #balance,
+                    include_fee: bool,
+                ) -> Option<#balance> {
+                    #asset_conversion::quote_price_exact_tokens_for_tokens(asset1, asset2, amount, include_fee)
+                }
+
+                fn quote_price_tokens_for_exact_tokens(
+                    asset1: <#runtime as pallet_asset_conversion::Config>::AssetKind,
+                    asset2: <#runtime as pallet_asset_conversion::Config>::AssetKind,
+                    amount: #balance,
+                    include_fee: bool,
+                ) -> Option<#balance> {
+                    #asset_conversion::quote_price_tokens_for_exact_tokens(asset1, asset2, amount, include_fee)
+                }
+
+                fn get_reserves(
+                    asset1: <#runtime as pallet_asset_conversion::Config>::AssetKind,
+                    asset2: <#runtime as pallet_asset_conversion::Config>::AssetKind,
+                ) -> Option<(#balance, #balance)> {
+                    #asset_conversion::get_reserves(asset1, asset2).ok()
+                }
+            }
+        });
     }
+
+    res
 }
@@ -1,4 +1,4 @@
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Span};
 use quote::quote;
 use syn::Item;
 
@@ -137,7 +137,7 @@ impl TryFrom<&[Item]> for XCMBenchmarkAPIFields {
         let asset_id = asset_id.ok_or("type `AssetId` not specified, but required")?;
         let xcm_config = xcm_config.ok_or("type `XCMConfig` not specified, but required")?;
         let account_id = account_id.ok_or("type `AccountId` not specified, but required")?;
-        let cents = cents.ok_or("type `Cents` not specified, but required")?;
+        let cents = cents.unwrap_or_else(|| Ident::new("CENTS", Span::call_site()));
         let fee_asset_id = fee_asset_id.ok_or("type `FeeAssetId` not specified, but required")?;
         let transaction_byte_fee =
             transaction_byte_fee.ok_or("type `TransactionByteFee` not specified, but required")?;
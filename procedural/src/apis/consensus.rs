@@ -1,4 +1,4 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{Ident, Item};
 
@@ -7,12 +7,7 @@ use super::fetch_ident;
 #[derive(Debug)]
 pub struct ConsensusAPIFields {
     pub session_keys: Ident,
-    #[cfg(not(feature = "async-backing"))]
-    pub aura: Ident,
-    #[cfg(feature = "async-backing")]
-    pub slot_duration: Ident,
-    #[cfg(feature = "async-backing")]
-    pub consensus_hook: Ident,
+    pub staking_backend: Ident,
 }
 
 impl TryFrom<&[Item]> for ConsensusAPIFields {
@@ -20,54 +15,31 @@ impl TryFrom<&[Item]> for ConsensusAPIFields {
 
     fn try_from(value: &[Item]) -> Result<Self, Self::Error> {
         let mut session_keys = None;
-
-        #[cfg(not(feature = "async-backing"))]
-        let mut aura = None;
-        #[cfg(feature = "async-backing")]
-        let mut slot_duration = None;
-        #[cfg(feature = "async-backing")]
-        let mut consensus_hook = None;
+        let mut staking_backend = None;
 
         for item in value {
             if let Item::Type(ty) = item {
                 let typ = ty.ty.clone();
                 if ty.ident == "SessionKeys" {
                     session_keys = Some(fetch_ident(&typ))
-                }
-
-                #[cfg(not(feature = "async-backing"))]
-                if ty.ident == "Aura" {
-                    aura = Some(fetch_ident(&typ))
-                }
-
-                #[cfg(feature = "async-backing")]
-                if ty.ident == "SlotDuration" {
-                    slot_duration = Some(fetch_ident(&typ))
-                } else if ty.ident == "ConsensusHook" {
-                    consensus_hook = Some(fetch_ident(&typ))
+                } else if ty.ident == "StakingBackend" {
+                    staking_backend = Some(fetch_ident(&typ))
                 }
             }
         }
         let session_keys = session_keys.ok_or("type `SessionKeys` not specified, but required")?;
-
-        #[cfg(not(feature = "async-backing"))]
-        {
-            let aura = aura.ok_or("type `Aura` not specified, but required")?;
-            Ok(ConsensusAPIFields { session_keys, aura })
+        let staking_backend =
+            staking_backend.unwrap_or_else(|| Ident::new("CollatorSelection", Span::call_site()));
+        if staking_backend != "CollatorSelection" && staking_backend != "CollatorStaking" {
+            return Err(
+                "type `StakingBackend` should be either `CollatorSelection` or `CollatorStaking`",
+            );
         }
 
-        #[cfg(feature = "async-backing")]
-        {
-            let slot_duration =
-                slot_duration.ok_or("type `SlotDuration` not specified, but required")?;
-            let consensus_hook =
-                consensus_hook.ok_or("type `ConsensusHook` not specified, but required")?;
-            Ok(ConsensusAPIFields {
-                session_keys,
-                slot_duration,
-                consensus_hook,
-            })
-        }
+        Ok(ConsensusAPIFields {
+            session_keys,
+            staking_backend,
+        })
     }
 }
 
@@ -75,28 +47,15 @@ pub fn consensus_apis(
     runtime: &Ident,
     block: &Ident,
     session_keys: &Ident,
-    #[cfg(not(feature = "async-backing"))] aura: &Ident,
-    #[cfg(feature = "async-backing")] slot_duration: &Ident,
-    #[cfg(feature = "async-backing")] consensus_hook: &Ident,
+    staking_backend: &Ident,
 ) -> TokenStream {
-    #[cfg(feature = "async-backing")]
-    let slot_duration = quote! {
-        return sp_consensus_aura::SlotDuration::from_millis(#slot_duration);
-    };
-    #[cfg(not(feature = "async-backing"))]
-    let slot_duration = quote! {
-        sp_consensus_aura::SlotDuration::from_millis(#aura::slot_duration())
-    };
-
-    let mut res = quote! {};
-
-    res.extend(quote! {
-        impl sp_consensus_aura::AuraApi<#block, sp_consensus_aura::sr25519::AuthorityId> for #runtime {
+    let mut res = quote! {
+        impl sp_consensus_aura::AuraApi<#block, <#runtime as pallet_aura::Config>::AuthorityId> for #runtime {
             fn slot_duration() -> sp_consensus_aura::SlotDuration {
-                #slot_duration
+                sp_consensus_aura::SlotDuration::from_millis(pallet_aura::Pallet::<#runtime>::slot_duration())
             }
 
-            fn authorities() -> sp_std::prelude::Vec<sp_consensus_aura::sr25519::AuthorityId> {
+            fn authorities() -> sp_std::prelude::Vec<<#runtime as pallet_aura::Config>::AuthorityId> {
                 pallet_aura::Authorities::<#runtime>::get().into_inner()
             }
         }
@@ -110,18 +69,127 @@ pub fn consensus_apis(
                 #session_keys::decode_into_raw_public_keys(&encoded)
             }
         }
-    });
-    #[cfg(feature = "async-backing")]
-    res.extend(quote! {
+
+        // Uses the consensus hook of the runtime, so authoring always agrees with the checks of
+        // `cumulus_pallet_parachain_system`.
         impl cumulus_primitives_aura::AuraUnincludedSegmentApi<#block> for #runtime {
             fn can_build_upon(
                 included_hash: <#block as sp_runtime::traits::Block>::Hash,
                 slot: cumulus_primitives_aura::Slot,
             ) -> bool {
-                #consensus_hook::can_build_upon(included_hash, slot)
+                <#runtime as cumulus_pallet_parachain_system::Config>::ConsensusHook::can_build_upon(
+                    included_hash,
+                    slot,
+                )
             }
         }
-    });
+    };
+
+    if staking_backend == "CollatorStaking" {
+        res.extend(collator_staking_apis(runtime, block));
+    } else {
+        res.extend(collator_selection_apis(runtime, block));
+    }
 
     res
 }
+
+fn collator_selection_apis(runtime: &Ident, block: &Ident) -> TokenStream {
+    quote! {
+        impl openzeppelin_pallet_abstractions::consensus::CollatorSelectionApi<
+            #block,
+            <#runtime as frame_system::Config>::AccountId,
+            <#runtime as pallet_balances::Config>::Balance,
+            frame_system::pallet_prelude::BlockNumberFor<#runtime>,
+        > for #runtime {
+            fn invulnerables() -> sp_std::prelude::Vec<<#runtime as frame_system::Config>::AccountId> {
+                pallet_collator_selection::Invulnerables::<#runtime>::get().into_inner()
+            }
+
+            fn candidates() -> sp_std::prelude::Vec<(
+                <#runtime as frame_system::Config>::AccountId,
+                <#runtime as pallet_balances::Config>::Balance,
+            )> {
+                pallet_collator_selection::CandidateList::<#runtime>::get()
+                    .into_iter()
+                    .map(|info| (info.who, info.deposit))
+                    .collect()
+            }
+
+            fn desired_candidates() -> u32 {
+                pallet_collator_selection::DesiredCandidates::<#runtime>::get()
+            }
+
+            fn candidacy_bond() -> <#runtime as pallet_balances::Config>::Balance {
+                pallet_collator_selection::CandidacyBond::<#runtime>::get()
+            }
+
+            fn kick_threshold_status(
+                who: <#runtime as frame_system::Config>::AccountId,
+            ) -> Option<openzeppelin_pallet_abstractions::consensus::KickThresholdStatus<
+                frame_system::pallet_prelude::BlockNumberFor<#runtime>,
+            >> {
+                use frame_support::traits::Get;
+                use sp_runtime::traits::Saturating;
+
+                if !pallet_collator_selection::CandidateList::<#runtime>::get()
+                    .iter()
+                    .any(|info| info.who == who)
+                {
+                    return None;
+                }
+                let last_authored_block = pallet_collator_selection::LastAuthoredBlock::<#runtime>::get(&who);
+                let kick_threshold = <#runtime as pallet_collator_selection::Config>::KickThreshold::get();
+                let now = frame_system::Pallet::<#runtime>::block_number();
+                Some(openzeppelin_pallet_abstractions::consensus::KickThresholdStatus {
+                    last_authored_block,
+                    kick_threshold_block: last_authored_block.saturating_add(kick_threshold),
+                    past_threshold: now.saturating_sub(last_authored_block) >= kick_threshold,
+                })
+            }
+
+            fn next_session_collators() -> sp_std::prelude::Vec<<#runtime as frame_system::Config>::AccountId> {
+                pallet_session::QueuedKeys::<#runtime>::get()
+                    .into_iter()
+                    .map(|(collator, _)| collator)
+                    .collect()
+            }
+        }
+    }
+}
+
+fn collator_staking_apis(runtime: &Ident, block: &Ident) -> TokenStream {
+    quote! {
+        impl openzeppelin_pallet_abstractions::consensus::CollatorStakingApi<
+            #block,
+            <#runtime as frame_system::Config>::AccountId,
+            <#runtime as pallet_balances::Config>::Balance,
+        > for #runtime {
+            fn candidates() -> sp_std::prelude::Vec<(
+                <#runtime as frame_system::Config>::AccountId,
+                <#runtime as pallet_balances::Config>::Balance,
+                <#runtime as pallet_balances::Config>::Balance,
+            )> {
+                pallet_collator_staking::Candidates::<#runtime>::iter()
+                    .map(|(who, info)| (who, info.deposit, info.stake))
+                    .collect()
+            }
+
+            fn delegations(
+                staker: <#runtime as frame_system::Config>::AccountId,
+            ) -> sp_std::prelude::Vec<(
+                <#runtime as frame_system::Config>::AccountId,
+                <#runtime as pallet_balances::Config>::Balance,
+            )> {
+                pallet_collator_staking::UserStake::<#runtime>::get(&staker)
+                    .candidates
+                    .into_iter()
+                    .map(|candidate| {
+                        let stake = pallet_collator_staking::CandidateStake::<#runtime>::get(&candidate, &staker).stake;
+                        (candidate, stake)
+                    })
+                    .collect()
+            }
+        }
+    }
+}
@@ -4,6 +4,7 @@ mod consensus;
 mod evm;
 mod system;
 mod tanssi;
+mod vesting;
 
 pub use assets::*;
 pub use benchmark::*;
@@ -11,6 +12,7 @@ pub use consensus::*;
 pub use evm::*;
 pub use system::*;
 pub use tanssi::*;
+pub use vesting::*;
 
 use syn::{Ident, Type};
 
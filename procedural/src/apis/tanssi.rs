@@ -1,4 +1,4 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{Ident, Item};
 
@@ -6,7 +6,9 @@ use super::fetch_ident;
 
 #[derive(Debug)]
 pub struct TanssiAPIFields {
-    pub session_keys: Ident,
+    pub session_keys: Option<Ident>,
+    pub system: Ident,
+    pub author_inherent: Ident,
 }
 
 impl TryFrom<&[Item]> for TanssiAPIFields {
@@ -14,37 +16,84 @@ impl TryFrom<&[Item]> for TanssiAPIFields {
 
     fn try_from(value: &[Item]) -> Result<Self, Self::Error> {
         let mut session_keys = None;
+        let mut system = None;
+        let mut author_inherent = None;
 
         for item in value {
-            match item {
-                Item::Type(ty) => {
-                    let typ = ty.ty.clone();
-                    if ty.ident == "SessionKeys" {
-                        session_keys = Some(fetch_ident(&typ))
-                    }
+            if let Item::Type(ty) = item {
+                let typ = ty.ty.clone();
+                if ty.ident == "SessionKeys" {
+                    session_keys = Some(fetch_ident(&typ))
+                } else if ty.ident == "System" {
+                    system = Some(fetch_ident(&typ))
+                } else if ty.ident == "AuthorInherent" {
+                    author_inherent = Some(fetch_ident(&typ))
                 }
-                _ => (),
             }
         }
-        let session_keys = session_keys.ok_or("type `SessionKeys` not specified, but required")?;
+        let system = system.unwrap_or_else(|| Ident::new("System", Span::call_site()));
+        let author_inherent =
+            author_inherent.unwrap_or_else(|| Ident::new("AuthorInherent", Span::call_site()));
 
-        return Ok(TanssiAPIFields { session_keys });
+        Ok(TanssiAPIFields {
+            session_keys,
+            system,
+            author_inherent,
+        })
     }
 }
 
-pub fn tanssi_apis(runtime: &Ident, block: &Ident, session_keys: &Ident) -> TokenStream {
-    let mut res = quote! {};
-    res.extend(quote! {
-        impl sp_session::SessionKeys<#block> for #runtime {
-            fn generate_session_keys(seed: Option<sp_std::prelude::Vec<u8>>) -> sp_std::prelude::Vec<u8> {
-                #session_keys::generate(seed)
+pub fn tanssi_apis(
+    runtime: &Ident,
+    block: &Ident,
+    session_keys: &Option<Ident>,
+    system: &Ident,
+    author_inherent: &Ident,
+) -> TokenStream {
+    let mut res = quote! {
+        impl nimbus_primitives::NimbusApi<#block> for #runtime {
+            fn can_author(
+                author: nimbus_primitives::NimbusId,
+                relay_parent: u32,
+                parent_header: &<#block as sp_runtime::traits::Block>::Header,
+            ) -> bool {
+                use sp_runtime::traits::Header;
+
+                // `System::initialize` only sets the number, parent hash and digest of the next block, which
+                // `can_author` may read. The authorities are still the ones noted in the parent block, the
+                // authorities noting inherent of the next block has not run yet.
+                let block_number = *parent_header.number() + 1;
+                #system::initialize(&block_number, &parent_header.hash(), parent_header.digest());
+                #author_inherent::can_author(&author, &relay_parent)
             }
+        }
 
-            fn decode_session_keys(encoded: sp_std::prelude::Vec<u8>) -> Option<sp_std::prelude::Vec<(sp_std::prelude::Vec<u8>, sp_core::crypto::KeyTypeId)>> {
-                #session_keys::decode_into_raw_public_keys(&encoded)
+        impl openzeppelin_pallet_abstractions::tanssi::TanssiAuthoritiesApi<#block, nimbus_primitives::NimbusId>
+            for #runtime
+        {
+            fn authorities() -> sp_std::prelude::Vec<nimbus_primitives::NimbusId> {
+                pallet_cc_authorities_noting::Pallet::<#runtime>::authorities()
+            }
+
+            fn is_authority(author: nimbus_primitives::NimbusId) -> bool {
+                pallet_cc_authorities_noting::Pallet::<#runtime>::authorities().contains(&author)
             }
         }
-    });
+    };
+
+    if let Some(session_keys) = session_keys {
+        res.extend(quote! {
+            impl sp_session::SessionKeys<#block> for #runtime {
+                fn generate_session_keys(seed: Option<sp_std::prelude::Vec<u8>>) -> sp_std::prelude::Vec<u8> {
+                    #session_keys::generate(seed)
+                }
+
+                fn decode_session_keys(encoded: sp_std::prelude::Vec<u8>) -> Option<sp_std::prelude::Vec<(sp_std::prelude::Vec<u8>, sp_core::crypto::KeyTypeId)>> {
+                    #session_keys::decode_into_raw_public_keys(&encoded)
+                }
+            }
+        });
+    }
 
     res
 }
@@ -0,0 +1,42 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+pub fn vesting_apis(runtime: &Ident, block: &Ident) -> TokenStream {
+    quote! {
+        impl openzeppelin_pallet_abstractions::vesting::VestingApi<
+            #block,
+            <#runtime as frame_system::Config>::AccountId,
+            <#runtime as pallet_balances::Config>::Balance,
+            frame_system::pallet_prelude::BlockNumberFor<#runtime>,
+        > for #runtime {
+            fn vesting_schedules(
+                who: <#runtime as frame_system::Config>::AccountId,
+            ) -> sp_std::prelude::Vec<openzeppelin_pallet_abstractions::vesting::VestingSchedule<
+                <#runtime as pallet_balances::Config>::Balance,
+                frame_system::pallet_prelude::BlockNumberFor<#runtime>,
+            >> {
+                pallet_vesting::Vesting::<#runtime>::get(&who)
+                    .map(|schedules| {
+                        schedules
+                            .into_iter()
+                            .map(|schedule| openzeppelin_pallet_abstractions::vesting::VestingSchedule {
+                                locked: schedule.locked(),
+                                per_block: schedule.per_block(),
+                                starting_block: schedule.starting_block(),
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            }
+
+            fn vesting_balance(
+                who: <#runtime as frame_system::Config>::AccountId,
+            ) -> Option<<#runtime as pallet_balances::Config>::Balance> {
+                <pallet_vesting::Pallet<#runtime> as frame_support::traits::VestingSchedule<
+                    <#runtime as frame_system::Config>::AccountId,
+                >>::vesting_balance(&who)
+            }
+        }
+    }
+}
@@ -61,7 +61,13 @@ fn parse_abstraction(
 
     match abstraction_name {
         ConstructAbstractions::System => (construct_system(index), None),
+        ConstructAbstractions::MultiBlockMigrations => (construct_migrations(index), None),
+        ConstructAbstractions::Deposits => (construct_deposits(index), None),
         ConstructAbstractions::Assets => (construct_assets(index), None),
+        ConstructAbstractions::AssetConversion => (construct_asset_conversion(index), None),
+        ConstructAbstractions::ForeignAssets => (construct_foreign_assets(index), None),
+        ConstructAbstractions::AssetFeeAccounting => (construct_asset_fee_accounting(index), None),
+        ConstructAbstractions::Rewards => (construct_rewards(index), None),
         ConstructAbstractions::Consensus => (
             construct_consensus(index),
             Some(quote! {
@@ -71,7 +77,19 @@ fn parse_abstraction(
                 }
             }),
         ),
+        ConstructAbstractions::ConsensusStaking => (
+            construct_consensus_staking(index),
+            Some(quote! {
+                cumulus_pallet_parachain_system::register_validate_block! {
+                    Runtime = Runtime,
+                    BlockExecutor = cumulus_pallet_aura_ext::BlockExecutor::<Runtime, Executive>,
+                }
+            }),
+        ),
         ConstructAbstractions::Governance => (construct_governance(index), None),
+        ConstructAbstractions::Nfts => (construct_nfts(index), None),
+        ConstructAbstractions::Vesting => (construct_vesting(index), None),
+        ConstructAbstractions::MaintenanceMode => (construct_maintenance_mode(index), None),
         ConstructAbstractions::Xcm => (construct_xcm(index), None),
         ConstructAbstractions::Evm => (construct_evm(index), None),
         ConstructAbstractions::Tanssi => (
@@ -123,6 +141,13 @@ fn construct_tanssi(index: &mut u32) -> proc_macro2::TokenStream {
     )
 }
 
+fn construct_maintenance_mode(index: &mut u32) -> proc_macro2::TokenStream {
+    construct_abstraction(
+        index,
+        &openzeppelin_pallet_abstractions::tanssi::MAINTENANCE_PALLET_NAMES,
+    )
+}
+
 fn construct_governance(index: &mut u32) -> proc_macro2::TokenStream {
     construct_abstraction(
         index,
@@ -137,6 +162,13 @@ fn construct_consensus(index: &mut u32) -> proc_macro2::TokenStream {
     )
 }
 
+fn construct_consensus_staking(index: &mut u32) -> proc_macro2::TokenStream {
+    construct_abstraction(
+        index,
+        &openzeppelin_pallet_abstractions::consensus::STAKING_PALLET_NAMES,
+    )
+}
+
 fn construct_evm(index: &mut u32) -> proc_macro2::TokenStream {
     construct_abstraction(index, &openzeppelin_pallet_abstractions::evm::PALLET_NAMES)
 }
@@ -148,6 +180,45 @@ fn construct_assets(index: &mut u32) -> proc_macro2::TokenStream {
     )
 }
 
+fn construct_asset_conversion(index: &mut u32) -> proc_macro2::TokenStream {
+    construct_abstraction(
+        index,
+        &openzeppelin_pallet_abstractions::assets::ASSET_CONVERSION_PALLET_NAMES,
+    )
+}
+
+fn construct_foreign_assets(index: &mut u32) -> proc_macro2::TokenStream {
+    construct_abstraction(
+        index,
+        &openzeppelin_pallet_abstractions::assets::FOREIGN_ASSETS_PALLET_NAMES,
+    )
+}
+
+fn construct_asset_fee_accounting(index: &mut u32) -> proc_macro2::TokenStream {
+    construct_abstraction(
+        index,
+        &openzeppelin_pallet_abstractions::assets::ASSET_FEE_ACCOUNTING_PALLET_NAMES,
+    )
+}
+
+fn construct_rewards(index: &mut u32) -> proc_macro2::TokenStream {
+    construct_abstraction(
+        index,
+        &openzeppelin_pallet_abstractions::consensus::REWARDS_PALLET_NAMES,
+    )
+}
+
+fn construct_nfts(index: &mut u32) -> proc_macro2::TokenStream {
+    construct_abstraction(index, &openzeppelin_pallet_abstractions::nfts::PALLET_NAMES)
+}
+
+fn construct_vesting(index: &mut u32) -> proc_macro2::TokenStream {
+    construct_abstraction(
+        index,
+        &openzeppelin_pallet_abstractions::vesting::PALLET_NAMES,
+    )
+}
+
 fn construct_system(index: &mut u32) -> proc_macro2::TokenStream {
     construct_abstraction(
         index,
@@ -155,19 +226,37 @@ fn construct_system(index: &mut u32) -> proc_macro2::TokenStream {
     )
 }
 
+fn construct_migrations(index: &mut u32) -> proc_macro2::TokenStream {
+    construct_abstraction(
+        index,
+        &openzeppelin_pallet_abstractions::system::MIGRATIONS_PALLET_NAMES,
+    )
+}
+
+fn construct_deposits(index: &mut u32) -> proc_macro2::TokenStream {
+    construct_abstraction(
+        index,
+        &openzeppelin_pallet_abstractions::system::DEPOSITS_PALLET_NAMES,
+    )
+}
+
 fn construct_abstraction(index: &mut u32, pallets: &[(&str, &str)]) -> proc_macro2::TokenStream {
     let mut res = quote! {};
     for (name, module) in pallets {
         res.extend(construct_pallet(
             index,
             construct_ident(name),
-            construct_ident(module),
+            construct_module(module),
         ));
     }
     res
 }
 
-fn construct_pallet(index: &mut u32, name: Ident, ty: Ident) -> proc_macro2::TokenStream {
+fn construct_pallet(
+    index: &mut u32,
+    name: Ident,
+    ty: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
     let index_literal = Literal::u32_unsuffixed(*index);
     *index += 1;
     quote! {
@@ -180,6 +269,13 @@ fn construct_ident(name: &str) -> syn::Ident {
     Ident::new(name, Span::call_site())
 }
 
+// Pallet modules may be instantiated, e.g. `pallet_assets<Instance2>`.
+fn construct_module(module: &str) -> proc_macro2::TokenStream {
+    module
+        .parse()
+        .unwrap_or_else(|_| panic!("Malformed pallet module: {module}"))
+}
+
 fn parse_pallet(item: ItemType, index: &mut u32) -> proc_macro2::TokenStream {
     let is_pallet = item.attrs.iter().any(|f| {
         let Ok(path) = f.meta.require_path_only() else {
@@ -33,16 +33,39 @@ mod runtime_apis;
 ///     * `cumulus_pallet_parachain_system`
 ///     * `pallet_multisig`
 ///     * `pallet_session`
+/// * `MultiBlockMigrations` (used with the `MultiBlockMigrations` mode of `impl_openzeppelin_system!`)
+///     * `pallet_migrations`
+/// * `Deposits` (used together with `System`)
+///     * `openzeppelin_pallet_abstractions::deposits`
 /// * `Assets`
 ///     * `pallet_assets`
 ///     * `pallet_transaction_payment`
 ///     * `pallet_asset_manager`
+/// * `AssetConversion`
+///     * `pallet_assets`
+///     * `pallet_transaction_payment`
+///     * `pallet_asset_manager`
+///     * `pallet_assets<Instance2>` (liquidity pool tokens)
+///     * `pallet_asset_conversion`
+///     * `pallet_asset_conversion_tx_payment`
+/// * `ForeignAssets` (used together with `Assets` or `AssetConversion`)
+///     * `pallet_assets<Instance3>` (foreign assets keyed by location)
+/// * `AssetFeeAccounting` (used together with `Assets`)
+///     * `openzeppelin_pallet_abstractions::asset_fee_accounting`
 /// * `Consensus`
 ///     * `pallet_authorship`
 ///     * `pallet_aura`
 ///     * `cumulus_pallet_aura_ext`
 ///     * `pallet_collator_selection`
-///     * `pallet_session
+///     * `pallet_session`
+/// * `ConsensusStaking`
+///     * `pallet_authorship`
+///     * `pallet_aura`
+///     * `cumulus_pallet_aura_ext`
+///     * `pallet_collator_staking`
+///     * `pallet_session`
+/// * `Rewards` (used together with `Consensus` or `ConsensusStaking`)
+///     * `openzeppelin_pallet_abstractions::rewards`
 /// * `Governance`
 ///     * `pallet_sudo`
 ///     * `pallet_treasury`
@@ -50,6 +73,8 @@ mod runtime_apis;
 ///     * `pallet_whitelist`
 ///     * `pallet_custom_origins`
 ///     * `pallet_referenda`
+/// * `Nfts`
+///     * `pallet_nfts`
 /// * `XCM`
 ///     * `pallet_message_queue`
 ///     * `cumulus_pallet_xcmp_queue`
@@ -64,6 +89,13 @@ mod runtime_apis;
 ///     * `pallet_base_fee`
 ///     * `pallet_evm_chain_id`
 ///     * `pallet_erc20_xcm_bridge`
+/// * `Tanssi`
+///     * `pallet_author_inherent`
+///     * `pallet_cc_authorities_noting`
+/// * `MaintenanceMode` (used with the `MaintenanceMode` mode of `impl_openzeppelin_tanssi!`)
+///     * `pallet_maintenance_mode`
+/// * `Vesting`
+///     * `pallet_vesting`
 #[proc_macro_attribute]
 pub fn openzeppelin_construct_runtime(_: TokenStream, tokens: TokenStream) -> TokenStream {
     construct_runtime::construct_openzeppelin_runtime(tokens)
@@ -106,20 +138,40 @@ pub fn openzeppelin_construct_runtime(_: TokenStream, tokens: TokenStream) -> To
 ///   * Implemented APIs:
 ///     * `pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi`
 ///     * `pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi`
+///     * `openzeppelin_pallet_abstractions::assets::AssetFeeApi` (`query_fee_in_asset` quotes with the same conversion the fee is charged with)
+///     * `orml_oracle_runtime_api::OracleApi` (if `Oracle` is specified)
+///     * `pallet_asset_conversion::AssetConversionApi` (if `AssetConversion` is specified)
 ///   * Required Types:
 ///     * `TransactionPayment` -- `pallet_transaction_payment` struct pallet generated by `construct_runtime` macro
 ///     * `RuntimeCall` -- runtime call generated by `construct_runtime` macro
 ///     * `Balance` -- type used for balance specification (e.g. in `pallet_balances` config)
+///     * `Oracle` -- `orml_oracle` pallet struct generated by `construct_runtime` macro. Optional, implements `orml_oracle_runtime_api::OracleApi`
+///     * `OracleKey` -- key type of `orml_oracle` (e.g. `AssetId`). Required with `Oracle`
+///     * `AssetConversion` -- `pallet_asset_conversion` pallet struct generated by `construct_runtime` macro. Optional, implements `pallet_asset_conversion::AssetConversionApi`
+///     * `AssetFees` -- type implementing `openzeppelin_pallet_abstractions::assets::FeeAssets`. Optional, defaults to `AssetFees` generated by the Assets abstraction
 /// * `consensus`
 ///   * Implemented APIs:
-///     * `sp_consensus_aura::AuraApi`
+///     * `sp_consensus_aura::AuraApi` (slot duration and authority id are taken from `pallet_aura::Config`)
 ///     * `sp_session::SessionKeys`
-///     * `cumulus_primitives_aura::AuraUnincludedSegmentApi` (if `async-backing` feature is enabled)
+///     * `cumulus_primitives_aura::AuraUnincludedSegmentApi` (uses `cumulus_pallet_parachain_system::Config::ConsensusHook`)
+///     * `openzeppelin_pallet_abstractions::consensus::CollatorSelectionApi` (if `StakingBackend` is `CollatorSelection`)
+///     * `openzeppelin_pallet_abstractions::consensus::CollatorStakingApi` (if `StakingBackend` is `CollatorStaking`)
 ///   * Required Types:
 ///     * `SessionKeys` -- struct generated by `impl_opaque_keys` macro
-///     * `Aura` -- `pallet_aura` struct pallet generated by `construct_runtime` macro (only if `async-backing` feature is not enabled)
-///     * `SlotDuration` -- constant that is use for slot duration definition (only if `async-backing` feature is enabled)
-///     * `ConsensusHook` -- type that is used in `cumulus_pallet_parachain_system::Config::ConsensusHook` (only if `async-backing` feature is enabled)
+///     * `StakingBackend` -- staking backend passed to `impl_openzeppelin_consensus!`, either `CollatorSelection` or `CollatorStaking`. Optional, defaults to `CollatorSelection`
+/// * `tanssi`
+///   * Implemented APIs:
+///     * `nimbus_primitives::NimbusApi` (`can_author` checks the authorities noted by `pallet_cc_authorities_noting`)
+///     * `openzeppelin_pallet_abstractions::tanssi::TanssiAuthoritiesApi` (the authorities noted by `pallet_cc_authorities_noting`)
+///     * `sp_session::SessionKeys` (if `SessionKeys` is specified)
+///   * Required Types:
+///     * `SessionKeys` -- struct generated by `impl_opaque_keys` macro. Optional
+///     * `System` -- `frame_system` pallet struct generated by `construct_runtime` macro. Optional, defaults to `System`
+///     * `AuthorInherent` -- `pallet_author_inherent` pallet struct generated by `construct_runtime` macro. Optional, defaults to `AuthorInherent`
+/// * `vesting`
+///   * Implemented APIs:
+///     * `openzeppelin_pallet_abstractions::vesting::VestingApi`
+///   * Required Types: none
 /// * `system`
 ///   * Implemented APIs:
 ///     * `sp_api::Core`
@@ -155,7 +207,7 @@ pub fn openzeppelin_construct_runtime(_: TokenStream, tokens: TokenStream) -> To
 ///     * `AssetId` -- type that describes internal asset id (e.g `AssetId` passet to `AssetsConfig`)
 ///     * `XCMConfig` -- struct that implements `xcm_executor::Config`. If you are using pallet abstractions it is generated by XCM abstraction and called `XcmExecutorConfig`
 ///     * `AccountId` -- account id type that was specified in `frame_system::Config`
-///     * `Cents` -- constant that represents 1/100 of your native token.
+///     * `Cents` -- constant that represents 1/100 of your native token. Optional, defaults to `CENTS`, e.g. generated by `impl_openzeppelin_currency_units!`.
 ///     * `FeeAssetId` -- type that describes an asset to pay XCM fees in. If you used an abstraction macro for XCM support, it was generated along the way and named `FeeAssetId`.
 ///     * `TransactionByteFee` -- type that describes fee per byte of data. If you used an abstraction macro for assets support it was generated with the same name.
 ///     * `Address` -- type that describes address format for describing accounts.
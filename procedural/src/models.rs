@@ -3,12 +3,22 @@ use syn::ItemStruct;
 
 pub enum ConstructAbstractions {
     Assets,
+    AssetConversion,
+    ForeignAssets,
+    AssetFeeAccounting,
     Xcm,
     Evm,
     System,
+    MultiBlockMigrations,
+    Deposits,
     Governance,
+    Nfts,
     Consensus,
+    ConsensusStaking,
+    Rewards,
     Tanssi,
+    MaintenanceMode,
+    Vesting,
 }
 
 #[derive(Debug)]
@@ -28,18 +38,38 @@ impl TryFrom<Ident> for ConstructAbstractions {
     fn try_from(value: Ident) -> Result<Self, Self::Error> {
         if "Assets".eq_ignore_ascii_case(&value.to_string()) {
             Ok(ConstructAbstractions::Assets)
+        } else if "AssetConversion".eq_ignore_ascii_case(&value.to_string()) {
+            Ok(ConstructAbstractions::AssetConversion)
+        } else if "ForeignAssets".eq_ignore_ascii_case(&value.to_string()) {
+            Ok(ConstructAbstractions::ForeignAssets)
+        } else if "AssetFeeAccounting".eq_ignore_ascii_case(&value.to_string()) {
+            Ok(ConstructAbstractions::AssetFeeAccounting)
         } else if "XCM".eq_ignore_ascii_case(&value.to_string()) {
             Ok(ConstructAbstractions::Xcm)
         } else if "EVM".eq_ignore_ascii_case(&value.to_string()) {
             Ok(ConstructAbstractions::Evm)
         } else if "System".eq_ignore_ascii_case(&value.to_string()) {
             Ok(ConstructAbstractions::System)
+        } else if "MultiBlockMigrations".eq_ignore_ascii_case(&value.to_string()) {
+            Ok(ConstructAbstractions::MultiBlockMigrations)
+        } else if "Deposits".eq_ignore_ascii_case(&value.to_string()) {
+            Ok(ConstructAbstractions::Deposits)
         } else if "Governance".eq_ignore_ascii_case(&value.to_string()) {
             Ok(ConstructAbstractions::Governance)
+        } else if "Nfts".eq_ignore_ascii_case(&value.to_string()) {
+            Ok(ConstructAbstractions::Nfts)
         } else if "Consensus".eq_ignore_ascii_case(&value.to_string()) {
             Ok(ConstructAbstractions::Consensus)
+        } else if "ConsensusStaking".eq_ignore_ascii_case(&value.to_string()) {
+            Ok(ConstructAbstractions::ConsensusStaking)
+        } else if "Rewards".eq_ignore_ascii_case(&value.to_string()) {
+            Ok(ConstructAbstractions::Rewards)
         } else if "Tanssi".eq_ignore_ascii_case(&value.to_string()) {
             Ok(ConstructAbstractions::Tanssi)
+        } else if "MaintenanceMode".eq_ignore_ascii_case(&value.to_string()) {
+            Ok(ConstructAbstractions::MaintenanceMode)
+        } else if "Vesting".eq_ignore_ascii_case(&value.to_string()) {
+            Ok(ConstructAbstractions::Vesting)
         } else {
             Err(ConversionError::UnknownAbstraction)
         }
@@ -53,6 +83,7 @@ pub enum APIAbstractions {
     Consensus,
     Assets,
     Tanssi,
+    Vesting,
 }
 
 impl TryFrom<Ident> for APIAbstractions {
@@ -70,6 +101,8 @@ impl TryFrom<Ident> for APIAbstractions {
             Ok(APIAbstractions::Consensus)
         } else if "Tanssi".eq_ignore_ascii_case(&value.to_string()) {
             Ok(APIAbstractions::Tanssi)
+        } else if "Vesting".eq_ignore_ascii_case(&value.to_string()) {
+            Ok(APIAbstractions::Vesting)
         } else {
             Err(ConversionError::UnknownAbstraction)
         }
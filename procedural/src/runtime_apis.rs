@@ -104,37 +104,32 @@ fn construct_abstraction(
                 balance,
                 call,
                 oracle_key,
-                oracle
+                oracle,
+                asset_conversion,
+                asset_fees,
             } = AssetAPIFields::try_from(content.as_slice())
                 .expect("Error while parsing assets config");
 
-            apis::assets_apis(runtime, block, &transaction_payment, &balance, &call, &oracle_key, &oracle)
+            apis::assets_apis(
+                runtime,
+                block,
+                &transaction_payment,
+                &balance,
+                &call,
+                &oracle_key,
+                &oracle,
+                &asset_conversion,
+                &asset_fees,
+            )
         }
         APIAbstractions::Consensus => {
             state.consensus = true;
-            #[cfg(not(feature = "async-backing"))]
-            {
-                let ConsensusAPIFields { session_keys, aura } =
-                    ConsensusAPIFields::try_from(content.as_slice())
-                        .expect("Error while parsing Consensus config");
-                apis::consensus_apis(runtime, block, &session_keys, &aura)
-            }
-            #[cfg(feature = "async-backing")]
-            {
-                let ConsensusAPIFields {
-                    session_keys,
-                    slot_duration,
-                    consensus_hook,
-                } = ConsensusAPIFields::try_from(content.as_slice())
-                    .expect("Error while parsing Consensus config");
-                apis::consensus_apis(
-                    runtime,
-                    block,
-                    &session_keys,
-                    &slot_duration,
-                    &consensus_hook,
-                )
-            }
+            let ConsensusAPIFields {
+                session_keys,
+                staking_backend,
+            } = ConsensusAPIFields::try_from(content.as_slice())
+                .expect("Error while parsing Consensus config");
+            apis::consensus_apis(runtime, block, &session_keys, &staking_backend)
         }
         APIAbstractions::System => {
             let SystemAPIFields {
@@ -170,9 +165,14 @@ fn construct_abstraction(
             quote! {}
         }
         APIAbstractions::Tanssi => {
-            let TanssiAPIFields { session_keys } = TanssiAPIFields::try_from(content.as_slice())
+            let TanssiAPIFields {
+                session_keys,
+                system,
+                author_inherent,
+            } = TanssiAPIFields::try_from(content.as_slice())
                 .expect("Error while parsing Tanssi config");
-            apis::tanssi_apis(runtime, block, &session_keys)
+            apis::tanssi_apis(runtime, block, &session_keys, &system, &author_inherent)
         }
+        APIAbstractions::Vesting => apis::vesting_apis(runtime, block),
     }
 }
@@ -0,0 +1,237 @@
+//! Pallet keeping the cumulative transaction fees paid in every non-native asset.
+//!
+//! It implements [`OnAssetFeePaid`], so it is passed as `AssetsConfig::OnAssetFeePaid` and configured with
+//! [`impl_openzeppelin_asset_fee_accounting!`](crate::impl_openzeppelin_asset_fee_accounting). Every payment
+//! is added to the [`FeeTotals`] of its asset in [`AssetFeeTotals`] and deposits an [`Event::AssetFeePaid`].
+//!
+//! Fees are reported after the dispatch of the transaction, so the weight of every payment is registered
+//! as mandatory extra weight of the block.
+//!
+//! Only the `Oracle` fee payment mode reports fees. In the `AssetConversion` mode, fees are charged by
+//! `pallet_asset_conversion_tx_payment::AssetConversionAdapter` without calling `AssetsConfig::OnAssetFeePaid`,
+//! so this pallet records nothing there. The payments are still visible in the `AssetTxFeePaid` event of
+//! `pallet_asset_conversion_tx_payment` and the swap events of `pallet_asset_conversion`.
+
+pub use pallet::*;
+
+use frame_support::{dispatch::DispatchClass, weights::Weight};
+
+use crate::assets::{AssetFeePaid, FeeTotals, OnAssetFeePaid};
+
+#[frame_support::pallet]
+pub mod pallet {
+    use frame_support::pallet_prelude::*;
+    use sp_runtime::{traits::Saturating, FixedU128};
+
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Identifier of the assets paying fees.
+        type AssetId: Parameter + MaxEncodedLen;
+        /// Balance of the native currency and of the assets.
+        type Balance: Parameter + MaxEncodedLen + Saturating + Copy + Default;
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Cumulative fees paid in every asset.
+    #[pallet::storage]
+    pub type AssetFeeTotals<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, FeeTotals<T::Balance>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A transaction fee was paid in a non-native asset. Amounts in `asset` include the tip.
+        AssetFeePaid {
+            who: T::AccountId,
+            asset: T::AssetId,
+            native_fee: T::Balance,
+            asset_fee: T::Balance,
+            tip: T::Balance,
+            refund: T::Balance,
+            price: Option<FixedU128>,
+        },
+    }
+}
+
+impl<T: Config> OnAssetFeePaid<T::AccountId, T::AssetId, T::Balance> for Pallet<T> {
+    fn on_asset_fee_paid(fee: &AssetFeePaid<T::AccountId, T::AssetId, T::Balance>) {
+        AssetFeeTotals::<T>::mutate(fee.asset.clone(), |totals| totals.note(fee));
+        Self::deposit_event(Event::AssetFeePaid {
+            who: fee.who.clone(),
+            asset: fee.asset.clone(),
+            native_fee: fee.native_fee,
+            asset_fee: fee.asset_fee,
+            tip: fee.tip,
+            refund: fee.refund,
+            price: fee.price,
+        });
+        frame_system::Pallet::<T>::register_extra_weight_unchecked(
+            T::WeightInfo::on_asset_fee_paid(),
+            DispatchClass::Mandatory,
+        );
+    }
+}
+
+pub trait WeightInfo {
+    fn on_asset_fee_paid() -> Weight;
+}
+
+/// Hand-written estimate, not the output of the benchmark below: one storage map read and write plus the
+/// event, with a fixed execution time. Runtimes should run the `on_asset_fee_paid` benchmark and pass the
+/// generated weights as `AssetsWeight::AssetFeeAccounting`.
+impl WeightInfo for () {
+    // Storage: `AssetFeeAccounting::AssetFeeTotals` (r:1 w:1)
+    // Storage: `System::Events` (w:1)
+    fn on_asset_fee_paid() -> Weight {
+        Weight::from_parts(20_000_000, 3_600).saturating_add(
+            frame_support::weights::constants::RocksDbWeight::get().reads_writes(1, 2),
+        )
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking {
+    use frame_benchmarking::v2::*;
+    use sp_runtime::FixedU128;
+
+    use super::*;
+
+    #[benchmarks(where T::AssetId: Default, T::Balance: From<u32>)]
+    mod benchmarks {
+        use super::*;
+
+        #[benchmark]
+        fn on_asset_fee_paid() {
+            let fee = AssetFeePaid {
+                who: whitelisted_caller(),
+                asset: T::AssetId::default(),
+                native_fee: 1_000u32.into(),
+                asset_fee: 100u32.into(),
+                tip: 10u32.into(),
+                refund: 5u32.into(),
+                price: Some(FixedU128::from_u32(10)),
+            };
+
+            #[block]
+            {
+                <Pallet<T> as OnAssetFeePaid<_, _, _>>::on_asset_fee_paid(&fee);
+            }
+
+            assert_eq!(AssetFeeTotals::<T>::get(T::AssetId::default()).payments, 1);
+        }
+
+        impl_benchmark_test_suite!(
+            Pallet,
+            crate::asset_fee_accounting::tests::new_test_ext(),
+            crate::asset_fee_accounting::tests::Test
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use frame_support::derive_impl;
+    use sp_runtime::{BuildStorage, FixedU128};
+
+    use super::*;
+
+    type Block = frame_system::mocking::MockBlock<Test>;
+
+    frame_support::construct_runtime!(
+        pub enum Test {
+            System: frame_system,
+            AssetFeeAccounting: crate::asset_fee_accounting,
+        }
+    );
+
+    #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+    impl frame_system::Config for Test {
+        type Block = Block;
+    }
+
+    impl Config for Test {
+        type AssetId = u32;
+        type Balance = u64;
+        type RuntimeEvent = RuntimeEvent;
+        type WeightInfo = ();
+    }
+
+    pub fn new_test_ext() -> sp_io::TestExternalities {
+        let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap()
+            .into();
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+
+    fn fee(asset: u32, native_fee: u64, asset_fee: u64, tip: u64) -> AssetFeePaid<u64, u32, u64> {
+        AssetFeePaid {
+            who: 1,
+            asset,
+            native_fee,
+            asset_fee,
+            tip,
+            refund: 0,
+            price: Some(FixedU128::from_u32(10)),
+        }
+    }
+
+    #[test]
+    fn payments_are_added_to_the_totals_of_their_asset() {
+        new_test_ext().execute_with(|| {
+            AssetFeeAccounting::on_asset_fee_paid(&fee(1, 1_000, 100, 10));
+            AssetFeeAccounting::on_asset_fee_paid(&fee(1, 500, 50, 0));
+            AssetFeeAccounting::on_asset_fee_paid(&fee(2, 200, 20, 2));
+
+            assert_eq!(
+                AssetFeeTotals::<Test>::get(1),
+                FeeTotals {
+                    native_fees: 1_500,
+                    asset_fees: 150,
+                    tips: 10,
+                    payments: 2
+                }
+            );
+            assert_eq!(
+                AssetFeeTotals::<Test>::get(2),
+                FeeTotals {
+                    native_fees: 200,
+                    asset_fees: 20,
+                    tips: 2,
+                    payments: 1
+                }
+            );
+        });
+    }
+
+    #[test]
+    fn payments_deposit_an_event_and_register_their_weight() {
+        new_test_ext().execute_with(|| {
+            AssetFeeAccounting::on_asset_fee_paid(&fee(1, 1_000, 100, 10));
+
+            System::assert_last_event(
+                Event::AssetFeePaid {
+                    who: 1,
+                    asset: 1,
+                    native_fee: 1_000,
+                    asset_fee: 100,
+                    tip: 10,
+                    refund: 0,
+                    price: Some(FixedU128::from_u32(10)),
+                }
+                .into(),
+            );
+            assert_eq!(
+                *frame_system::BlockWeight::<Test>::get().get(DispatchClass::Mandatory),
+                <() as WeightInfo>::on_asset_fee_paid()
+            );
+        });
+    }
+}
@@ -4,22 +4,67 @@
 //! - `pallet_assets`
 //! - `pallet_transaction_payment`
 //! - `pallet_asset_manager`
-//! - `orml_oracle`
-//! - `pallet_asset_tx_payment`
-//! - `pallet_membership`
+//! - `orml_oracle`, `pallet_asset_tx_payment` and `pallet_membership` in the `Oracle` mode
+//! - `pallet_asset_conversion`, `pallet_asset_conversion_tx_payment` and a second `pallet_assets`
+//!   instance for liquidity pool tokens in the `AssetConversion` mode
 //!
 //! # Parameters
 //! - `$t`: A type that implements the `AssetsConfig` trait, providing the necessary associated types
 //!   and configurations.
+//! - fee payment mode (optional): `Oracle` (default) converts fees with prices fed to `orml_oracle` and
+//!   keeps them in the paid asset. `AssetConversion` swaps fees to the native token through the liquidity
+//!   pool of the paid asset, so any asset with liquidity can pay fees without an oracle. Use
+//!   [`PALLET_NAMES`] or [`ASSET_CONVERSION_PALLET_NAMES`] (`Assets` or `AssetConversion` in
+//!   `construct_openzeppelin_runtime`) accordingly.
+//!
+//! In the `Oracle` mode, the asset paying the fee is identified by `AssetsConfig::FeeAssetId`. The default
+//! `MultiLocation` only accepts foreign assets, while [`FeeAsset`] also accepts locally created
+//! `pallet_assets` tokens. In both modes, the generated `AssetFees` type lists the assets that can pay fees,
+//! which is exposed to wallets by [`AssetFeeApi`].
+//!
+//! Fees are paid from `FeeFungibles`, identified by `FeeAssetKind`. By default this is the `Assets`
+//! instance, where foreign assets are registered in `pallet_asset_manager`. With the `ForeignAssets` flag
+//! (e.g. `impl_openzeppelin_assets!(Runtime, Oracle, ForeignAssets)`), it joins the `Assets` and
+//! `ForeignAssets` instances of [`impl_openzeppelin_foreign_assets!`] and `FeeAssetKind` is [`FeeAsset`]. In
+//! the `Oracle` mode foreign assets are then priced under the id `pallet_asset_manager` derives from their
+//! location.
+//!
+//! Every fee paid in the `Oracle` mode is reported to `AssetsConfig::OnAssetFeePaid` as an [`AssetFeePaid`],
+//! with the native value of the fee and the oracle price it was converted with. Pass the
+//! `AssetFeeAccounting` pallet configured by [`impl_openzeppelin_asset_fee_accounting!`] there to keep the
+//! per-asset [`FeeTotals`] and deposit an event for every payment. `pallet_asset_tx_payment` still deposits
+//! its own `AssetTxFeePaid` event. Fees paid in the `AssetConversion` mode are not reported: they are charged
+//! by `pallet_asset_conversion_tx_payment::AssetConversionAdapter`, which has no such hook, and are only
+//! visible in the `AssetTxFeePaid` event of `pallet_asset_conversion_tx_payment` and the swap events of
+//! `pallet_asset_conversion`.
+//!
+//! Foreign assets keyed by their XCM location can be kept in a separate instance with
+//! [`impl_openzeppelin_foreign_assets!`].
 //!
 //! # Important
 //! Rerun benchmarks if making changes to runtime configuration, as weight calculations
 //! may need to be updated.
 
+use ::xcm::{
+    latest::{Junction, Location},
+    v3::MultiLocation,
+};
+use core::marker::PhantomData;
+
+use cumulus_primitives_core::ParaId;
+use frame_support::traits::{tokens::ConversionToAssetBalance, Contains, Get};
+use sp_runtime::{
+    traits::{Convert, MaybeEquivalence, One, Saturating, Zero},
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+    FixedU128,
+};
+use sp_std::prelude::Vec;
+
 #[macro_export]
 #[allow(clippy::crate_in_macro_def)]
 macro_rules! impl_openzeppelin_assets {
-    ($t:ty) => {
+    // Pallets shared by every fee payment mode.
+    (@common $t:ty) => {
         // Constants for assets configuration
         parameter_types! {
             // The maximum length of a name or symbol stored on-chain.
@@ -46,6 +91,19 @@ macro_rules! impl_openzeppelin_assets {
             }
         }
 
+        pub struct AssetsCallbacks;
+        impl pallet_assets::AssetsCallback<<$t as AssetsConfig>::AssetId, AccountId> for AssetsCallbacks {
+            fn created(id: &<$t as AssetsConfig>::AssetId, owner: &AccountId) -> Result<(), ()> {
+                <<$t as AssetsConfig>::AssetLifecycleHooks as $crate::assets::OnAssetLifecycle<_, _>>::on_created(id, owner);
+                Ok(())
+            }
+
+            fn destroyed(id: &<$t as AssetsConfig>::AssetId) -> Result<(), ()> {
+                <<$t as AssetsConfig>::AssetLifecycleHooks as $crate::assets::OnAssetLifecycle<_, _>>::on_destroyed(id);
+                Ok(())
+            }
+        }
+
         impl pallet_assets::Config for Runtime {
             // The amount of funds that must be reserved when creating a new approval.
             type ApprovalDeposit = <$t as AssetsConfig>::ApprovalDeposit;
@@ -61,7 +119,8 @@ macro_rules! impl_openzeppelin_assets {
             type Balance = Balance;
             #[cfg(feature = "runtime-benchmarks")]
             type BenchmarkHelper = BenchmarkHelper;
-            type CallbackHandle = ();
+            // Runs `AssetsConfig::AssetLifecycleHooks` when an asset is created or destroyed.
+            type CallbackHandle = AssetsCallbacks;
             // Standard asset class creation is only allowed if the origin attempting it and the
             // asset class are in this set.
             type CreateOrigin = <$t as AssetsConfig>::CreateOrigin;
@@ -70,7 +129,8 @@ macro_rules! impl_openzeppelin_assets {
             // The origin which may forcibly create or destroy an asset or otherwise alter privileged
 	    // attributes.
             type ForceOrigin = <$t as AssetsConfig>::ForceOrigin;
-            type Freezer = ();
+            // Frozen balance of every account, e.g. vesting or governance locks on local tokens.
+            type Freezer = <$t as AssetsConfig>::Freezer;
             type MetadataDepositBase = MetadataDepositBase;
             type MetadataDepositPerByte = MetadataDepositPerByte;
             type RemoveItemsLimit = RemoveItemsLimit;
@@ -112,14 +172,180 @@ macro_rules! impl_openzeppelin_assets {
             type RuntimeEvent = RuntimeEvent;
             type WeightInfo = <$t as AssetsWeight>::AssetManager;
         }
+    };
+
+    // Assets paying transaction fees: `FeeFungibles` with the asset identifier `FeeAssetKind`. Without the
+    // `ForeignAssets` flag only the `Assets` instance pays fees.
+    (@fee_fungibles $t:ty) => {
+        pub type FeeAssetKind = <$t as AssetsConfig>::AssetId;
+        pub type FeeFungibles = crate::Assets;
+    };
+    (@fee_fungibles $t:ty, ForeignAssets) => {
+        pub type FeeAssetKind = $crate::assets::FeeAsset<<$t as AssetsConfig>::AssetId>;
+        // Local tokens are paid from the `Assets` instance, foreign assets from the `ForeignAssets` instance.
+        pub type FeeFungibles = frame_support::traits::fungibles::UnionOf<
+            crate::Assets,
+            crate::ForeignAssets,
+            FeeAssetInstance,
+            FeeAssetKind,
+            AccountId,
+        >;
+
+        // Instance holding a fee asset. Foreign locations that are not kept in `ForeignAssets` (see
+        // `is_foreign_asset_location`), e.g. the relay chain token, are paid from their `pallet_asset_manager`
+        // registration in the `Assets` instance.
+        pub struct FeeAssetInstance;
+        impl sp_runtime::traits::Convert<FeeAssetKind, sp_runtime::Either<<$t as AssetsConfig>::AssetId, $crate::assets::ForeignAssetId>>
+            for FeeAssetInstance
+        {
+            fn convert(asset: FeeAssetKind) -> sp_runtime::Either<<$t as AssetsConfig>::AssetId, $crate::assets::ForeignAssetId> {
+                match asset {
+                    $crate::assets::FeeAsset::Local(asset_id) => sp_runtime::Either::Left(asset_id),
+                    $crate::assets::FeeAsset::Foreign(location)
+                        if <$crate::assets::LocationToV3 as sp_runtime::traits::MaybeEquivalence<_, _>>::convert_back(&location)
+                            .is_some_and(|location| {
+                                $crate::assets::is_foreign_asset_location(
+                                    &location,
+                                    <parachain_info::Pallet<Runtime> as frame_support::traits::Get<_>>::get(),
+                                )
+                            }) =>
+                        sp_runtime::Either::Right(location),
+                    $crate::assets::FeeAsset::Foreign(location) => sp_runtime::Either::Left(AssetType::Xcm(location).into()),
+                }
+            }
+        }
+    };
+    (@fee_fungibles $t:ty, $foreign:ident) => {
+        compile_error!(concat!(
+            "Unknown fee assets flag `",
+            stringify!($foreign),
+            "`. Available flags are ForeignAssets."
+        ));
+    };
+    // Fee assets of the `Oracle` mode. `AssetFees::resolve` maps the `AssetsConfig::FeeAssetId` of
+    // `ChargeAssetTxPayment` to `FeeAssetKind`, `FeeAssetOracleKey` maps it to its key in `orml_oracle`.
+    (@oracle_fee_assets $t:ty) => {
+        pub struct AssetFees;
+        impl AssetFees {
+            // Locally created assets are used as they are, foreign assets through their `AssetType`.
+            pub fn resolve(asset: <$t as AssetsConfig>::FeeAssetId) -> FeeAssetKind {
+                match $crate::assets::FeeAssetIdentifier::<<$t as AssetsConfig>::AssetId>::into_fee_asset(asset) {
+                    $crate::assets::FeeAsset::Local(asset_id) => asset_id,
+                    $crate::assets::FeeAsset::Foreign(location) => AssetType::Xcm(location).into(),
+                }
+            }
+        }
+
+        pub struct FeeAssetOracleKey;
+        impl sp_runtime::traits::Convert<FeeAssetKind, <$t as AssetsConfig>::AssetId> for FeeAssetOracleKey {
+            fn convert(asset: FeeAssetKind) -> <$t as AssetsConfig>::AssetId {
+                asset
+            }
+        }
+
+        impl $crate::assets::FeeAssets for AssetFees {
+            type AssetId = <$t as AssetsConfig>::FeeAssetId;
+            type Balance = Balance;
+
+            // Same conversion as `OnCharge`, so the quote matches the amount charged.
+            fn quote_fee(native_fee: Balance, asset: Self::AssetId) -> Option<Balance> {
+                $crate::assets::convert_fee::<AssetConverter, _, _, _>(native_fee, Self::resolve(asset)).ok()
+            }
+
+            // Assets with a price in the oracle.
+            fn supported_fee_assets() -> sp_std::prelude::Vec<Self::AssetId> {
+                Oracle::get_all_values()
+                    .into_iter()
+                    .filter(|(_, price)| price.is_some())
+                    .filter_map(|(asset_id, _)| {
+                        let location = pallet_asset_manager::AssetIdType::<Runtime>::get(&asset_id).and_then(
+                            #[allow(unreachable_patterns)]
+                            |asset_type| match asset_type {
+                                AssetType::Xcm(location) => Some(location),
+                                _ => None,
+                            },
+                        );
+                        <Self::AssetId as $crate::assets::FeeAssetIdentifier<<$t as AssetsConfig>::AssetId>>::from_fee_asset(
+                            $crate::assets::FeeAsset::Local(asset_id),
+                        )
+                        .or_else(|| {
+                            location.and_then(|location| {
+                                <Self::AssetId as $crate::assets::FeeAssetIdentifier<<$t as AssetsConfig>::AssetId>>::from_fee_asset(
+                                    $crate::assets::FeeAsset::Foreign(location),
+                                )
+                            })
+                        })
+                    })
+                    .collect()
+            }
+        }
+    };
+    (@oracle_fee_assets $t:ty, ForeignAssets) => {
+        pub struct AssetFees;
+        impl AssetFees {
+            pub fn resolve(asset: <$t as AssetsConfig>::FeeAssetId) -> FeeAssetKind {
+                $crate::assets::FeeAssetIdentifier::<<$t as AssetsConfig>::AssetId>::into_fee_asset(asset)
+            }
+        }
+
+        // Foreign assets are priced under the id `pallet_asset_manager` derives from their location.
+        pub struct FeeAssetOracleKey;
+        impl sp_runtime::traits::Convert<FeeAssetKind, <$t as AssetsConfig>::AssetId> for FeeAssetOracleKey {
+            fn convert(asset: FeeAssetKind) -> <$t as AssetsConfig>::AssetId {
+                match asset {
+                    $crate::assets::FeeAsset::Local(asset_id) => asset_id,
+                    $crate::assets::FeeAsset::Foreign(location) => AssetType::Xcm(location).into(),
+                }
+            }
+        }
+
+        impl $crate::assets::FeeAssets for AssetFees {
+            type AssetId = <$t as AssetsConfig>::FeeAssetId;
+            type Balance = Balance;
+
+            // Same conversion as `OnCharge`, so the quote matches the amount charged.
+            fn quote_fee(native_fee: Balance, asset: Self::AssetId) -> Option<Balance> {
+                $crate::assets::convert_fee::<AssetConverter, _, _, _>(native_fee, Self::resolve(asset)).ok()
+            }
+
+            // Local tokens and foreign assets of the `ForeignAssets` instance with a price in the oracle.
+            fn supported_fee_assets() -> sp_std::prelude::Vec<Self::AssetId> {
+                let local = Oracle::get_all_values()
+                    .into_iter()
+                    .filter(|(_, price)| price.is_some())
+                    .map(|(asset_id, _)| asset_id)
+                    .filter(|asset_id| {
+                        <crate::Assets as frame_support::traits::fungibles::Inspect<AccountId>>::asset_exists(asset_id.clone())
+                    })
+                    .map($crate::assets::FeeAsset::Local);
+                let foreign = <crate::ForeignAssets as frame_support::traits::fungibles::InspectEnumerable<AccountId>>::asset_ids()
+                    .map($crate::assets::FeeAsset::Foreign)
+                    .filter(|asset| {
+                        Oracle::get(&<FeeAssetOracleKey as sp_runtime::traits::Convert<_, _>>::convert(asset.clone())).is_some()
+                    });
+                local
+                    .chain(foreign)
+                    .filter_map(<Self::AssetId as $crate::assets::FeeAssetIdentifier<<$t as AssetsConfig>::AssetId>>::from_fee_asset)
+                    .collect()
+            }
+        }
+    };
+
+    ($t:ty) => {
+        $crate::impl_openzeppelin_assets!($t, Oracle);
+    };
+    ($t:ty, Oracle $(, $foreign:ident)?) => {
+        $crate::impl_openzeppelin_assets!(@common $t);
+        $crate::impl_openzeppelin_assets!(@fee_fungibles $t $(, $foreign)?);
+        $crate::impl_openzeppelin_assets!(@oracle_fee_assets $t $(, $foreign)?);
 
         pub struct AssetConverter;
 
-        impl frame_support::traits::tokens::ConversionToAssetBalance<Balance, <$t as AssetsConfig>::AssetId, Balance> for AssetConverter {
+        impl frame_support::traits::tokens::ConversionToAssetBalance<Balance, FeeAssetKind, Balance> for AssetConverter {
             type Error = sp_runtime::transaction_validity::InvalidTransaction;
 
-            fn to_asset_balance(balance: Balance, asset_id: <$t as AssetsConfig>::AssetId) -> Result<Balance, Self::Error> {
-                let funding_asset_price = Oracle::get(&asset_id)
+            fn to_asset_balance(balance: Balance, asset_id: FeeAssetKind) -> Result<Balance, Self::Error> {
+                let funding_asset_price = Oracle::get(&<FeeAssetOracleKey as sp_runtime::traits::Convert<_, _>>::convert(asset_id))
                     .ok_or(sp_runtime::transaction_validity::InvalidTransaction::Payment)?;
                 // FIXME: check if timestamp on oracle data is outdated.
 
@@ -149,13 +375,16 @@ macro_rules! impl_openzeppelin_assets {
             for TxFeeFungiblesAdapter<Converter, FeeCreditor, TipCreditor>
         where
             Runtime: pallet_asset_tx_payment::Config,
-            Runtime::Fungibles: frame_support::traits::fungibles::Inspect<parachains_common::impls::AccountIdOf<Runtime>, AssetId = <$t as AssetsConfig>::AssetId>,
+            Runtime::Fungibles: frame_support::traits::fungibles::Inspect<parachains_common::impls::AccountIdOf<Runtime>, AssetId = FeeAssetKind>,
             Converter: frame_support::traits::tokens::ConversionToAssetBalance<BalanceOf<Runtime>, AssetIdOf<Runtime>, AssetBalanceOf<Runtime>>,
             FeeCreditor: pallet_asset_tx_payment::HandleCredit<Runtime::AccountId, Runtime::Fungibles>,
             TipCreditor: pallet_asset_tx_payment::HandleCredit<Runtime::AccountId, Runtime::Fungibles>,
+            BalanceOf<Runtime>: Into<Balance>,
+            AssetBalanceOf<Runtime>: Into<Balance>,
+            <$t as AssetsConfig>::OnAssetFeePaid: $crate::assets::OnAssetFeePaid<Runtime::AccountId, FeeAssetKind, Balance>,
         {
-            // Note: We stick to `v3::MultiLocation`` because `v4::Location`` doesn't implement `Copy`.
-            type AssetId = xcm::v3::MultiLocation;
+            // Identifier of the fee asset in `ChargeAssetTxPayment`, see `AssetsConfig::FeeAssetId`.
+            type AssetId = <$t as AssetsConfig>::FeeAssetId;
             type Balance = BalanceOf<Runtime>;
             type LiquidityInfo = frame_support::traits::fungibles::Credit<Runtime::AccountId, Runtime::Fungibles>;
 
@@ -168,15 +397,8 @@ macro_rules! impl_openzeppelin_assets {
                 fee: Self::Balance,
                 _tip: Self::Balance,
             ) -> Result<Self::LiquidityInfo, sp_runtime::transaction_validity::TransactionValidityError> {
-                use sp_runtime::traits::Zero;
-                // We don't know the precision of the underlying asset. Because the converted fee could be
-                // less than one (e.g. 0.5) but gets rounded down by integer division we introduce a minimum
-                // fee.
-                let asset_id: AssetId = AssetType::Xcm(asset_id).into();
-                let min_converted_fee = if fee.is_zero() { sp_runtime::traits::Zero::zero() } else { sp_runtime::traits::One::one() };
-                let converted_fee = Converter::to_asset_balance(fee, asset_id.clone())
-                    .map_err(|_| sp_runtime::transaction_validity::TransactionValidityError::from(sp_runtime::transaction_validity::InvalidTransaction::Payment))?
-                    .max(min_converted_fee);
+                let asset_id = AssetFees::resolve(asset_id);
+                let converted_fee = $crate::assets::convert_fee::<Converter, _, _, _>(fee, asset_id.clone())?;
                 let can_withdraw =
                     <Runtime::Fungibles as frame_support::traits::fungibles::Inspect<Runtime::AccountId>>::can_withdraw(asset_id.clone(), who, converted_fee);
                 if can_withdraw != frame_support::traits::tokens::WithdrawConsequence::Success {
@@ -202,12 +424,8 @@ macro_rules! impl_openzeppelin_assets {
                 tip: Self::Balance,
                 paid: Self::LiquidityInfo,
             ) -> Result<(AssetBalanceOf<Runtime>, AssetBalanceOf<Runtime>), sp_runtime::transaction_validity::TransactionValidityError> {
-                use sp_runtime::traits::Zero;
-                let min_converted_fee = if corrected_fee.is_zero() { sp_runtime::traits::Zero::zero() } else { sp_runtime::traits::One::one() };
                 // Convert the corrected fee and tip into the asset used for payment.
-                let converted_fee = Converter::to_asset_balance(corrected_fee, paid.asset())
-                    .map_err(|_| sp_runtime::transaction_validity::TransactionValidityError::from(sp_runtime::transaction_validity::InvalidTransaction::Payment))?
-                    .max(min_converted_fee);
+                let converted_fee = $crate::assets::convert_fee::<Converter, _, _, _>(corrected_fee, paid.asset())?;
                 let converted_tip = Converter::to_asset_balance(tip, paid.asset())
                     .map_err(|_| sp_runtime::transaction_validity::TransactionValidityError::from(sp_runtime::transaction_validity::InvalidTransaction::Payment))?;
 
@@ -216,13 +434,27 @@ macro_rules! impl_openzeppelin_assets {
                 // Split the tip from the fee
                 let (final_tip, final_fee_minus_tip) = final_fee.split(converted_tip);
 
-                let _ = <Runtime::Fungibles as frame_support::traits::fungibles::Balanced<Runtime::AccountId>>::resolve(who, refund)
+                let fee_paid = $crate::assets::AssetFeePaid {
+                    who: who.clone(),
+                    asset: paid.asset(),
+                    native_fee: corrected_fee.into(),
+                    asset_fee: converted_fee.into(),
+                    tip: converted_tip.into(),
+                    refund: refund.peek().into(),
+                    // The price the fee was converted with, before non-zero fees are raised to one unit.
+                    price: Oracle::get(&<FeeAssetOracleKey as sp_runtime::traits::Convert<_, _>>::convert(paid.asset()))
+                        .map(|price| price.value),
+                };
+
+                <Runtime::Fungibles as frame_support::traits::fungibles::Balanced<Runtime::AccountId>>::resolve(who, refund)
                     // this case is unreachable
                     .map_err(|_| sp_runtime::transaction_validity::TransactionValidityError::from(sp_runtime::transaction_validity::InvalidTransaction::Payment))?;
 
                 FeeCreditor::handle_credit(final_fee_minus_tip);
                 TipCreditor::handle_credit(final_tip);
 
+                <<$t as AssetsConfig>::OnAssetFeePaid as $crate::assets::OnAssetFeePaid<_, _, _>>::on_asset_fee_paid(&fee_paid);
+
                 Ok((converted_fee, converted_tip))
             }
         }
@@ -233,7 +465,14 @@ macro_rules! impl_openzeppelin_assets {
         {
             fn handle_credit(credit: frame_support::traits::fungibles::Credit<AccountId, Assets>) {
                 let payee: AccountId = Account::get();
-                let _ = <Assets as frame_support::traits::fungibles::Balanced<AccountId>>::resolve(&payee, credit);
+                // Resolving only fails if the payee can not receive the asset (e.g. the credit is below the
+                // minimum balance of a new account), in which case the credit is burned.
+                if <Assets as frame_support::traits::fungibles::Balanced<AccountId>>::resolve(&payee, credit).is_err() {
+                    $crate::log::warn!(
+                        target: "runtime::assets",
+                        "Asset transaction fee could not be credited to `FungiblesToAccount` and was burned",
+                    );
+                }
             }
         }
 
@@ -241,14 +480,14 @@ macro_rules! impl_openzeppelin_assets {
             AssetConverter,
             CreditFungiblesToAccount<
                 <$t as AssetsConfig>::AccountId,
-                crate::Assets,
+                FeeFungibles,
                 <$t as AssetsConfig>::FungiblesToAccount
             >,
             <$t as AssetsConfig>::AssetsToBlockAuthor
         >;
 
         impl pallet_asset_tx_payment::Config for Runtime {
-            type Fungibles = crate::Assets;
+            type Fungibles = FeeFungibles;
             type OnChargeAssetTransaction = OnCharge;
             type RuntimeEvent = RuntimeEvent;
         }
@@ -289,6 +528,18 @@ macro_rules! impl_openzeppelin_assets {
             type BenchmarkHelper = OracleBenchmarkHelper;
         }
 
+        /// Access of [`OracleAssetCleanup`] to the prices of `orml_oracle`.
+        pub struct OrmlOraclePrices;
+        impl $crate::assets::AssetPrices<<$t as AssetsConfig>::AssetId> for OrmlOraclePrices {
+            fn remove_price(id: &<$t as AssetsConfig>::AssetId) {
+                orml_oracle::Values::<Runtime>::remove(id);
+            }
+        }
+
+        /// Asset lifecycle hook removing the price of destroyed assets from `orml_oracle`, see
+        /// `openzeppelin_pallet_abstractions::assets::PriceCleanup`.
+        pub type OracleAssetCleanup = $crate::assets::PriceCleanup<OrmlOraclePrices>;
+
         parameter_types! {
             pub const MaxMembers: u32 = 30;
         }
@@ -305,8 +556,642 @@ macro_rules! impl_openzeppelin_assets {
             type MaxMembers = MaxMembers;
             type WeightInfo = <$t as AssetsWeight>::OracleMembership;
         }
+    };
+    ($t:ty, AssetConversion $(, $foreign:ident)?) => {
+        $crate::impl_openzeppelin_assets!(@common $t);
+        $crate::impl_openzeppelin_assets!(@fee_fungibles $t $(, $foreign)?);
+
+        parameter_types! {
+            pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
+            // Only the asset conversion pallet can create the liquidity pool tokens.
+            pub AssetConversionOrigin: AccountId =
+                sp_runtime::traits::AccountIdConversion::<AccountId>::into_account_truncating(&AssetConversionPalletId::get());
+            pub const Native: frame_support::traits::tokens::fungible::NativeOrWithId<FeeAssetKind> =
+                frame_support::traits::tokens::fungible::NativeOrWithId::Native;
+            // Minimum amount of liquidity tokens minted when a pool receives its first liquidity.
+            pub const MintMinLiquidity: Balance = 100;
+        }
+
+        // Liquidity pool tokens of `pallet_asset_conversion`.
+        impl pallet_assets::Config<pallet_assets::Instance2> for Runtime {
+            type ApprovalDeposit = <$t as AssetsConfig>::ApprovalDeposit;
+            type AssetAccountDeposit = <$t as AssetsConfig>::AssetAccountDeposit;
+            // Pool tokens take no deposit. Creating a pool is charged `AssetsConfig::PoolSetupFee` instead, which
+            // must be non-zero to limit pool creation.
+            type AssetDeposit = frame_support::traits::ConstU128<0>;
+            type AssetId = u32;
+            type AssetIdParameter = parity_scale_codec::Compact<u32>;
+            type Balance = Balance;
+            #[cfg(feature = "runtime-benchmarks")]
+            type BenchmarkHelper = ();
+            type CallbackHandle = ();
+            type CreateOrigin = frame_support::traits::AsEnsureOriginWithArg<
+                frame_system::EnsureSignedBy<AssetConversionOrigin, AccountId>,
+            >;
+            type Currency = Balances;
+            type Extra = ();
+            type ForceOrigin = <$t as AssetsConfig>::ForceOrigin;
+            // Frozen balance of liquidity pool tokens.
+            type Freezer = <$t as AssetsConfig>::PoolAssetsFreezer;
+            type MetadataDepositBase = MetadataDepositBase;
+            type MetadataDepositPerByte = MetadataDepositPerByte;
+            type RemoveItemsLimit = RemoveItemsLimit;
+            type RuntimeEvent = RuntimeEvent;
+            type StringLimit = StringLimit;
+            type WeightInfo = <$t as AssetsWeight>::PoolAssets;
+        }
+
+        // The native token together with the `pallet_assets` tokens, as seen by the liquidity pools.
+        pub type NativeAndAssets = frame_support::traits::fungible::UnionOf<
+            Balances,
+            FeeFungibles,
+            frame_support::traits::fungible::NativeFromLeft,
+            frame_support::traits::tokens::fungible::NativeOrWithId<FeeAssetKind>,
+            AccountId,
+        >;
+
+        pub type PoolIdToAccountId = pallet_asset_conversion::AccountIdConverter<
+            AssetConversionPalletId,
+            (
+                frame_support::traits::tokens::fungible::NativeOrWithId<FeeAssetKind>,
+                frame_support::traits::tokens::fungible::NativeOrWithId<FeeAssetKind>,
+            ),
+        >;
+
+        impl pallet_asset_conversion::Config for Runtime {
+            type AssetKind = frame_support::traits::tokens::fungible::NativeOrWithId<FeeAssetKind>;
+            type Assets = NativeAndAssets;
+            type Balance = Balance;
+            #[cfg(feature = "runtime-benchmarks")]
+            type BenchmarkHelper = ();
+            type HigherPrecisionBalance = sp_core::U256;
+            // Fee of the liquidity providers, in thousandths.
+            type LPFee = <$t as AssetsConfig>::LPFee;
+            type LiquidityWithdrawalFee = <$t as AssetsConfig>::LiquidityWithdrawalFee;
+            type MaxSwapPathLength = <$t as AssetsConfig>::MaxSwapPathLength;
+            type MintMinLiquidity = MintMinLiquidity;
+            type PalletId = AssetConversionPalletId;
+            type PoolAssetId = u32;
+            type PoolAssets = PoolAssets;
+            type PoolId = (Self::AssetKind, Self::AssetKind);
+            // Every pool pairs an asset with the native token first, other pairs are ordered ascending.
+            type PoolLocator = pallet_asset_conversion::Chain<
+                pallet_asset_conversion::WithFirstAsset<Native, AccountId, Self::AssetKind, PoolIdToAccountId>,
+                pallet_asset_conversion::Ascending<AccountId, Self::AssetKind, PoolIdToAccountId>,
+            >;
+            // Fee paid in the native token by the account creating a pool.
+            type PoolSetupFee = <$t as AssetsConfig>::PoolSetupFee;
+            type PoolSetupFeeAsset = Native;
+            type PoolSetupFeeTarget = <$t as AssetsConfig>::PoolSetupFeeTarget;
+            type RuntimeEvent = RuntimeEvent;
+            type WeightInfo = <$t as AssetsWeight>::AssetConversion;
+        }
+
+        // Fees paid in an asset are swapped to the native token through its pool with the native token.
+        impl pallet_asset_conversion_tx_payment::Config for Runtime {
+            type Fungibles = FeeFungibles;
+            type OnChargeAssetTransaction =
+                pallet_asset_conversion_tx_payment::AssetConversionAdapter<Balances, AssetConversion, Native>;
+            type RuntimeEvent = RuntimeEvent;
+        }
+
+        // Fee assets accepted by `ChargeAssetTxPayment`.
+        pub struct AssetFees;
+        impl $crate::assets::FeeAssets for AssetFees {
+            type AssetId = FeeAssetKind;
+            type Balance = Balance;
+
+            // Amount of the asset swapped for the native fee by `AssetConversionAdapter`.
+            fn quote_fee(native_fee: Balance, asset: Self::AssetId) -> Option<Balance> {
+                if sp_runtime::traits::Zero::is_zero(&native_fee) {
+                    return Some(native_fee);
+                }
+                AssetConversion::quote_price_tokens_for_exact_tokens(
+                    frame_support::traits::tokens::fungible::NativeOrWithId::WithId(asset),
+                    Native::get(),
+                    native_fee,
+                    true,
+                )
+            }
+
+            // Assets with a liquidity pool with the native token.
+            fn supported_fee_assets() -> sp_std::prelude::Vec<Self::AssetId> {
+                pallet_asset_conversion::Pools::<Runtime>::iter_keys()
+                    .filter_map(|pool_id| match pool_id {
+                        (
+                            frame_support::traits::tokens::fungible::NativeOrWithId::Native,
+                            frame_support::traits::tokens::fungible::NativeOrWithId::WithId(asset_id),
+                        ) => Some(asset_id),
+                        _ => None,
+                    })
+                    .collect()
+            }
+        }
+    };
+    ($t:ty, $mode:ident $(, $foreign:ident)?) => {
+        compile_error!(concat!(
+            "Unknown fee payment mode `",
+            stringify!($mode),
+            "`. Available modes are Oracle, AssetConversion."
+        ));
+    };
+}
+
+/// Adds a second `pallet_assets` instance, `ForeignAssets`, for foreign assets keyed directly by their XCM
+/// location, next to the `Assets` instance of [`impl_openzeppelin_assets!`], which then only holds local
+/// trust-backed tokens. Use it together with the Assets and XCM abstractions, and add its pallets with
+/// [`FOREIGN_ASSETS_PALLET_NAMES`] (`ForeignAssets` in `construct_openzeppelin_runtime`).
+///
+/// Foreign assets are created by `AssetsConfig::ForceOrigin` with `force_create`. Their identifier is the
+/// `xcm::v3::Location` of the asset as seen from this chain, e.g. `(1, Parachain(1000), PalletInstance(50),
+/// GeneralIndex(1984))`, so no `pallet_asset_manager` registration is needed.
+///
+/// With the `Permissionless` mode, sibling parachains also register their own assets by sending a
+/// `create` call over XCM. The asset must be located under the sibling (see [`is_sibling_asset`]), and the
+/// sovereign account of the sibling, converted with `XcmConfig::LocationToAccountId`, becomes its owner and
+/// pays `AssetsConfig::AssetDeposit` and the metadata deposits. Metadata is limited to
+/// `AssetsConfig::ForeignAssetsStringLimit` bytes. `AssetsConfig::ForceOrigin` stays able to freeze spam
+/// assets with `force_asset_status` and to destroy them with `start_destroy`.
+///
+/// The `create` call is only accepted from `pallet_xcm::Origin::Xcm`, checked with `pallet_xcm::EnsureXcm`.
+/// This origin only exists if `XcmConfig::XcmOriginToTransactDispatchOrigin` contains
+/// `pallet_xcm::XcmPassthrough<RuntimeOrigin>` and the sibling sends its `Transact` with `OriginKind::Xcm`,
+/// otherwise the call is dispatched with another origin and every `create` fails. The barrier must also let siblings execute a paid `Transact`,
+/// which the `Barrier` of the XCM abstraction does with `AllowTopLevelPaidExecutionFrom<Everything>`.
+///
+/// The generated `LocalAssetsTransactor` (local tokens, matched as `(PalletInstance(<Assets index>),
+/// GeneralIndex(id))`) and `ForeignAssetsTransactor` (the locations of [`is_foreign_asset_location`]: assets
+/// of other consensus systems, except the relay chain token and locations under this parachain) should be
+/// added to `XcmConfig::AssetTransactors` after the native currency transactor, so that both instances can
+/// be transferred over XCM and pay XCM execution fees. The transactor of the assets registered in
+/// `pallet_asset_manager` goes after them and only handles the locations left, e.g. the relay chain token.
+///
+/// Pass the `ForeignAssets` flag to [`impl_openzeppelin_assets!`] (e.g.
+/// `impl_openzeppelin_assets!(Runtime, AssetConversion, ForeignAssets)`) to pay transaction fees from this
+/// instance as well. `FeeFungibles` then joins both instances and [`FeeAsset::Foreign`] is paid from
+/// `ForeignAssets`. `AssetsConfig::FeeAssetId` should be [`FeeAsset`] and `AssetsConfig::AssetsToBlockAuthor`
+/// must handle credits of `FeeFungibles` in the `Oracle` mode. In the `AssetConversion` mode pools pair the
+/// native token with `FeeAssetKind`, i.e. with local and foreign assets.
+#[macro_export]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! impl_openzeppelin_foreign_assets {
+    (@common $t:ty, $create_origin:ty) => {
+        pub type ForeignAssetsInstance = pallet_assets::Instance3;
+
+        pallet_assets::runtime_benchmarks_enabled! {
+            pub struct ForeignAssetsBenchmarkHelper;
+            impl pallet_assets::BenchmarkHelper<$crate::assets::ForeignAssetId> for ForeignAssetsBenchmarkHelper {
+                fn create_asset_id_parameter(id: u32) -> $crate::assets::ForeignAssetId {
+                    $crate::assets::foreign_asset_id_for_benchmarks(id)
+                }
+            }
+        }
+
+        pub struct ForeignAssetsCallbacks;
+        impl pallet_assets::AssetsCallback<$crate::assets::ForeignAssetId, AccountId> for ForeignAssetsCallbacks {
+            fn created(id: &$crate::assets::ForeignAssetId, owner: &AccountId) -> Result<(), ()> {
+                <<$t as AssetsConfig>::ForeignAssetLifecycleHooks as $crate::assets::OnAssetLifecycle<_, _>>::on_created(id, owner);
+                Ok(())
+            }
+
+            fn destroyed(id: &$crate::assets::ForeignAssetId) -> Result<(), ()> {
+                <<$t as AssetsConfig>::ForeignAssetLifecycleHooks as $crate::assets::OnAssetLifecycle<_, _>>::on_destroyed(id);
+                Ok(())
+            }
+        }
+
+        impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
+            type ApprovalDeposit = <$t as AssetsConfig>::ApprovalDeposit;
+            type AssetAccountDeposit = <$t as AssetsConfig>::AssetAccountDeposit;
+            type AssetDeposit = <$t as AssetsConfig>::AssetDeposit;
+            // Foreign assets are identified by their location.
+            type AssetId = $crate::assets::ForeignAssetId;
+            type AssetIdParameter = $crate::assets::ForeignAssetId;
+            type Balance = Balance;
+            #[cfg(feature = "runtime-benchmarks")]
+            type BenchmarkHelper = ForeignAssetsBenchmarkHelper;
+            type CallbackHandle = ForeignAssetsCallbacks;
+            type CreateOrigin = $create_origin;
+            type Currency = Balances;
+            type Extra = ();
+            type ForceOrigin = <$t as AssetsConfig>::ForceOrigin;
+            // Frozen balance of foreign assets.
+            type Freezer = <$t as AssetsConfig>::ForeignAssetsFreezer;
+            type MetadataDepositBase = MetadataDepositBase;
+            type MetadataDepositPerByte = MetadataDepositPerByte;
+            type RemoveItemsLimit = RemoveItemsLimit;
+            type RuntimeEvent = RuntimeEvent;
+            type StringLimit = <$t as AssetsConfig>::ForeignAssetsStringLimit;
+            type WeightInfo = <$t as AssetsWeight>::ForeignAssets;
+        }
+
+        parameter_types! {
+            // Location of the `Assets` instance, the prefix of local tokens.
+            pub TrustBackedAssetsPalletLocation: Location =
+                PalletInstance(<crate::Assets as frame_support::traits::PalletInfoAccess>::index() as u8).into();
+        }
+
+        /// Location of the local token `id`, for the lifecycle hooks of the `Assets` instance.
+        pub struct LocalAssetLocation;
+        impl sp_runtime::traits::Convert<<$t as AssetsConfig>::AssetId, Option<Location>> for LocalAssetLocation {
+            fn convert(id: <$t as AssetsConfig>::AssetId) -> Option<Location> {
+                let mut location = <TrustBackedAssetsPalletLocation as frame_support::traits::Get<Location>>::get();
+                location.push_interior(GeneralIndex(id.into())).ok()?;
+                Some(location)
+            }
+        }
+
+        /// Matches `(PalletInstance(<Assets index>), GeneralIndex(id))` to the local token `id`.
+        pub type LocalAssetsConvertedConcreteId = xcm_builder::MatchedConvertedConcreteId<
+            <$t as AssetsConfig>::AssetId,
+            Balance,
+            xcm_builder::StartsWith<TrustBackedAssetsPalletLocation>,
+            xcm_builder::AsPrefixedGeneralIndex<TrustBackedAssetsPalletLocation, <$t as AssetsConfig>::AssetId, xcm_executor::traits::JustTry>,
+            xcm_executor::traits::JustTry,
+        >;
+
+        /// Matches the locations kept in the `ForeignAssets` instance (see `is_foreign_asset_location`) to the
+        /// foreign asset with this location.
+        pub type ForeignAssetsConvertedConcreteId = xcm_builder::MatchedConvertedConcreteId<
+            $crate::assets::ForeignAssetId,
+            Balance,
+            $crate::assets::IsForeignAssetLocation<parachain_info::Pallet<Runtime>>,
+            $crate::assets::LocationToV3,
+            xcm_executor::traits::JustTry,
+        >;
+
+        /// Transacts local tokens of the `Assets` instance.
+        pub type LocalAssetsTransactor<LocationToAccountId> = xcm_builder::FungiblesAdapter<
+            crate::Assets,
+            LocalAssetsConvertedConcreteId,
+            LocationToAccountId,
+            AccountId,
+            xcm_builder::NoChecking,
+            PlaceholderAccount,
+        >;
+
+        /// Transacts foreign assets of the `ForeignAssets` instance.
+        pub type ForeignAssetsTransactor<LocationToAccountId> = xcm_builder::FungiblesAdapter<
+            crate::ForeignAssets,
+            ForeignAssetsConvertedConcreteId,
+            LocationToAccountId,
+            AccountId,
+            xcm_builder::NoChecking,
+            PlaceholderAccount,
+        >;
+    };
+    ($t:ty) => {
+        // Foreign assets can only be created with `force_create`.
+        $crate::impl_openzeppelin_foreign_assets!(
+            @common $t,
+            frame_support::traits::AsEnsureOriginWithArg<frame_support::traits::NeverEnsureOrigin<AccountId>>
+        );
+    };
+    ($t:ty, Permissionless) => {
+        /// Lets sibling parachains create foreign assets located under them over XCM. The sovereign account
+        /// of the sibling owns the asset and pays its deposit.
+        pub struct ForeignCreators;
+        impl frame_support::traits::EnsureOriginWithArg<RuntimeOrigin, $crate::assets::ForeignAssetId> for ForeignCreators {
+            type Success = AccountId;
+
+            fn try_origin(origin: RuntimeOrigin, asset: &$crate::assets::ForeignAssetId) -> Result<AccountId, RuntimeOrigin> {
+                let origin_location =
+                    <pallet_xcm::EnsureXcm<frame_support::traits::Everything> as frame_support::traits::EnsureOrigin<RuntimeOrigin>>::try_origin(origin.clone())?;
+                let Some(asset_location) =
+                    <$crate::assets::LocationToV3 as sp_runtime::traits::MaybeEquivalence<_, _>>::convert_back(asset)
+                else {
+                    return Err(origin);
+                };
+                if !$crate::assets::is_sibling_asset(&asset_location, &origin_location) {
+                    return Err(origin);
+                }
+                <<$t as XcmConfig>::LocationToAccountId as xcm_executor::traits::ConvertLocation<AccountId>>::convert_location(&origin_location)
+                    .ok_or(origin)
+            }
+
+            #[cfg(feature = "runtime-benchmarks")]
+            fn try_successful_origin(asset: &$crate::assets::ForeignAssetId) -> Result<RuntimeOrigin, ()> {
+                let asset_location =
+                    <$crate::assets::LocationToV3 as sp_runtime::traits::MaybeEquivalence<_, _>>::convert_back(asset).ok_or(())?;
+                let sibling = Location::new(1, [asset_location.first_interior().cloned().ok_or(())?]);
+                Ok(pallet_xcm::Origin::Xcm(sibling).into())
+            }
+        }
+
+        $crate::impl_openzeppelin_foreign_assets!(@common $t, ForeignCreators);
+    };
+    ($t:ty, $mode:ident) => {
+        compile_error!(concat!(
+            "Unknown foreign assets mode `",
+            stringify!($mode),
+            "`. Available modes are Permissionless."
+        ));
+    };
+}
+
+/// Configures the [`crate::asset_fee_accounting`] pallet, which keeps the cumulative fees paid in every asset
+/// and deposits an `AssetFeePaid` event for every payment. Pass it as `AssetsConfig::OnAssetFeePaid`
+/// (`type OnAssetFeePaid = AssetFeeAccounting;`) and add it with [`ASSET_FEE_ACCOUNTING_PALLET_NAMES`]
+/// (`AssetFeeAccounting` in `construct_openzeppelin_runtime`). Only fees of the `Oracle` mode are reported,
+/// with the `AssetConversion` mode the pallet records nothing.
+#[macro_export]
+macro_rules! impl_openzeppelin_asset_fee_accounting {
+    ($t:ty) => {
+        impl $crate::asset_fee_accounting::Config for Runtime {
+            type AssetId = FeeAssetKind;
+            type Balance = Balance;
+            type RuntimeEvent = RuntimeEvent;
+            type WeightInfo = <$t as AssetsWeight>::AssetFeeAccounting;
+        }
+    };
+}
+
+/// Whether `asset` belongs to the sibling parachain `origin`: `origin` must be `(1, Parachain(id))` and
+/// `asset` must be located under it.
+pub fn is_sibling_asset(asset: &Location, origin: &Location) -> bool {
+    matches!(origin.unpack(), (1, [Junction::Parachain(_)])) && asset.starts_with(origin)
+}
+
+/// Hook run when an asset is created or destroyed, see `AssetsConfig::AssetLifecycleHooks` and
+/// `AssetsConfig::ForeignAssetLifecycleHooks`. Hooks can not prevent the creation or destruction of an asset.
+///
+/// The abstractions generate hooks for the pallets that keep per-asset state:
+/// - `WeightTraderAssetRegistration` (XCM) adds assets created by trusted creators to
+///   `pallet_xcm_weight_trader`, see [`crate::xcm::WeightTraderRegistration`]
+/// - `Erc20AssetRegistration` (EVM) deploys the code of the ERC-20 precompile of created assets, see
+///   [`crate::evm::Erc20Registration`]
+/// - `OracleAssetCleanup` (Assets, `Oracle` mode) removes the price of destroyed assets, see [`PriceCleanup`]
+///
+/// Tuples of up to 8 hooks run them in order.
+pub trait OnAssetLifecycle<AssetId, AccountId> {
+    fn on_created(_id: &AssetId, _owner: &AccountId) {}
+    fn on_destroyed(_id: &AssetId) {}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+impl<AssetId, AccountId> OnAssetLifecycle<AssetId, AccountId> for Tuple {
+    fn on_created(id: &AssetId, owner: &AccountId) {
+        for_tuples!( #( Tuple::on_created(id, owner); )* );
+    }
+
+    fn on_destroyed(id: &AssetId) {
+        for_tuples!( #( Tuple::on_destroyed(id); )* );
+    }
+}
+
+/// Prices of assets, cleared by [`PriceCleanup`]. Implemented for `orml_oracle` by the `OrmlOraclePrices` type
+/// generated in the `Oracle` mode of [`impl_openzeppelin_assets!`].
+pub trait AssetPrices<AssetId> {
+    fn remove_price(id: &AssetId);
+}
+
+/// Asset lifecycle hook removing the price of destroyed assets from `Prices`, so that they stop being listed
+/// as fee assets. `orml_oracle` accepts values for any key, so nothing has to be registered when an asset is
+/// created.
+pub struct PriceCleanup<Prices>(PhantomData<Prices>);
+impl<AssetId, AccountId, Prices: AssetPrices<AssetId>> OnAssetLifecycle<AssetId, AccountId>
+    for PriceCleanup<Prices>
+{
+    fn on_destroyed(id: &AssetId) {
+        Prices::remove_price(id);
+    }
+}
+
+/// Identifier of the assets of the `ForeignAssets` instance generated by [`impl_openzeppelin_foreign_assets!`].
+pub type ForeignAssetId = MultiLocation;
+
+/// Whether `location` is kept in the `ForeignAssets` instance: an asset of another consensus system, except
+/// the relay chain token and locations under this parachain, `self_para_id`.
+pub fn is_foreign_asset_location(location: &Location, self_para_id: ParaId) -> bool {
+    match location.unpack() {
+        (0, _) | (1, []) => false,
+        (1, [Junction::Parachain(id), ..]) => ParaId::from(*id) != self_para_id,
+        _ => true,
+    }
+}
+
+/// Matches the locations of [`is_foreign_asset_location`], for `ForeignAssetsConvertedConcreteId`.
+pub struct IsForeignAssetLocation<SelfParaId>(PhantomData<SelfParaId>);
+impl<SelfParaId: Get<ParaId>> Contains<Location> for IsForeignAssetLocation<SelfParaId> {
+    fn contains(location: &Location) -> bool {
+        is_foreign_asset_location(location, SelfParaId::get())
+    }
+}
+
+/// Converts between the latest XCM `Location` and [`ForeignAssetId`].
+pub struct LocationToV3;
+impl MaybeEquivalence<Location, ForeignAssetId> for LocationToV3 {
+    fn convert(location: &Location) -> Option<ForeignAssetId> {
+        ForeignAssetId::try_from(location.clone()).ok()
+    }
+
+    fn convert_back(location: &ForeignAssetId) -> Option<Location> {
+        Location::try_from(*location).ok()
+    }
+}
+
+/// Location of a foreign asset, for the lifecycle hooks of the `ForeignAssets` instance.
+pub struct ForeignAssetLocation;
+impl Convert<ForeignAssetId, Option<Location>> for ForeignAssetLocation {
+    fn convert(id: ForeignAssetId) -> Option<Location> {
+        Location::try_from(id).ok()
+    }
+}
+
+/// Foreign asset id used by the `pallet_assets` benchmarks of the `ForeignAssets` instance.
+pub fn foreign_asset_id_for_benchmarks(id: u32) -> ForeignAssetId {
+    ForeignAssetId::new(1, ::xcm::v3::Junction::Parachain(id))
+}
+
+/// Identifier of an asset that can pay transaction fees, see `AssetsConfig::FeeAssetId`.
+///
+/// It must be `Copy` for `pallet_asset_tx_payment`, which rules out XCM v4 `Location`.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    parity_scale_codec::Encode,
+    parity_scale_codec::Decode,
+    parity_scale_codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum FeeAsset<AssetId> {
+    /// A locally created `pallet_assets` token.
+    Local(AssetId),
+    /// A foreign asset. It is paid from the `ForeignAssets` instance if the Assets abstraction is configured
+    /// with the `ForeignAssets` flag and the location is kept there, otherwise from its registration in
+    /// `pallet_asset_manager`.
+    Foreign(MultiLocation),
+}
+
+impl<AssetId> Default for FeeAsset<AssetId> {
+    fn default() -> Self {
+        FeeAsset::Foreign(MultiLocation::default())
+    }
+}
+
+impl<AssetId> From<MultiLocation> for FeeAsset<AssetId> {
+    fn from(location: MultiLocation) -> Self {
+        FeeAsset::Foreign(location)
+    }
+}
+
+/// Conversion between the fee asset identifier used in `ChargeAssetTxPayment` and [`FeeAsset`].
+pub trait FeeAssetIdentifier<AssetId>: Sized {
+    fn into_fee_asset(self) -> FeeAsset<AssetId>;
+    fn from_fee_asset(asset: FeeAsset<AssetId>) -> Option<Self>;
+}
+
+/// Foreign assets only, identified by their `MultiLocation`.
+impl<AssetId> FeeAssetIdentifier<AssetId> for MultiLocation {
+    fn into_fee_asset(self) -> FeeAsset<AssetId> {
+        FeeAsset::Foreign(self)
+    }
+
+    fn from_fee_asset(asset: FeeAsset<AssetId>) -> Option<Self> {
+        match asset {
+            FeeAsset::Foreign(location) => Some(location),
+            FeeAsset::Local(_) => None,
+        }
+    }
+}
+
+/// Both local and foreign assets.
+impl<AssetId> FeeAssetIdentifier<AssetId> for FeeAsset<AssetId> {
+    fn into_fee_asset(self) -> FeeAsset<AssetId> {
+        self
+    }
+
+    fn from_fee_asset(asset: FeeAsset<AssetId>) -> Option<Self> {
+        Some(asset)
+    }
+}
+
+/// Fee assets of the fee payment mode, implemented by the `AssetFees` type generated by
+/// `impl_openzeppelin_assets!`.
+pub trait FeeAssets {
+    /// Identifier of the fee asset in `ChargeAssetTxPayment`.
+    type AssetId;
+    type Balance;
+
+    /// Amount of `asset` charged for a fee of `native_fee`, or `None` if the asset can not pay fees.
+    fn quote_fee(native_fee: Self::Balance, asset: Self::AssetId) -> Option<Self::Balance>;
+    /// Assets that can currently pay transaction fees.
+    fn supported_fee_assets() -> Vec<Self::AssetId>;
+}
 
+/// Transaction fee paid in a non-native asset, reported to `AssetsConfig::OnAssetFeePaid` in the `Oracle`
+/// fee payment mode. Amounts in `asset` are charged amounts, the tip is included in both fees.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+    parity_scale_codec::Encode,
+    parity_scale_codec::Decode,
+    parity_scale_codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+)]
+pub struct AssetFeePaid<AccountId, AssetId, Balance> {
+    pub who: AccountId,
+    pub asset: AssetId,
+    /// Fee in the native currency.
+    pub native_fee: Balance,
+    /// Fee in `asset`.
+    pub asset_fee: Balance,
+    /// Tip in `asset`.
+    pub tip: Balance,
+    /// Amount of `asset` refunded to `who` after the dispatch.
+    pub refund: Balance,
+    /// Native price of one unit of `asset` in the oracle, that the fee was converted with.
+    pub price: Option<FixedU128>,
+}
+
+/// Handler of fees paid in non-native assets, e.g. a pallet depositing an event for every payment.
+pub trait OnAssetFeePaid<AccountId, AssetId, Balance> {
+    fn on_asset_fee_paid(fee: &AssetFeePaid<AccountId, AssetId, Balance>);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+impl<AccountId, AssetId, Balance> OnAssetFeePaid<AccountId, AssetId, Balance> for Tuple {
+    fn on_asset_fee_paid(fee: &AssetFeePaid<AccountId, AssetId, Balance>) {
+        for_tuples!( #( Tuple::on_asset_fee_paid(fee); )* );
+    }
+}
+
+/// Cumulative fees paid in an asset, kept by the [`crate::asset_fee_accounting`] pallet.
+#[derive(
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Debug,
+    parity_scale_codec::Encode,
+    parity_scale_codec::Decode,
+    parity_scale_codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+)]
+pub struct FeeTotals<Balance> {
+    /// Native value of the fees.
+    pub native_fees: Balance,
+    /// Fees charged in the asset, net of refunds.
+    pub asset_fees: Balance,
+    /// Tips charged in the asset.
+    pub tips: Balance,
+    /// Number of transactions that paid their fee in the asset.
+    pub payments: u64,
+}
+
+impl<Balance: Saturating + Copy> FeeTotals<Balance> {
+    pub fn note<AccountId, AssetId>(&mut self, fee: &AssetFeePaid<AccountId, AssetId, Balance>) {
+        self.native_fees = self.native_fees.saturating_add(fee.native_fee);
+        self.asset_fees = self.asset_fees.saturating_add(fee.asset_fee);
+        self.tips = self.tips.saturating_add(fee.tip);
+        self.payments = self.payments.saturating_add(1);
+    }
+}
+
+/// Converts a native fee into the fee asset. We don't know the precision of the underlying asset, so
+/// because the converted fee could be less than one (e.g. 0.5) but gets rounded down by integer division,
+/// non-zero fees cost at least one unit.
+pub fn convert_fee<Converter, NativeBalance, AssetId, AssetBalance>(
+    fee: NativeBalance,
+    asset_id: AssetId,
+) -> Result<AssetBalance, TransactionValidityError>
+where
+    Converter: ConversionToAssetBalance<NativeBalance, AssetId, AssetBalance>,
+    NativeBalance: Zero,
+    AssetBalance: Zero + One + Ord,
+{
+    let min_converted_fee = if fee.is_zero() {
+        Zero::zero()
+    } else {
+        One::one()
     };
+    Converter::to_asset_balance(fee, asset_id)
+        .map(|converted_fee| converted_fee.max(min_converted_fee))
+        .map_err(|_| InvalidTransaction::Payment.into())
+}
+
+sp_api::decl_runtime_apis! {
+    /// Fee payment in non-native assets, for wallets.
+    pub trait AssetFeeApi<AssetId, Balance>
+    where
+        AssetId: parity_scale_codec::Codec,
+        Balance: parity_scale_codec::Codec,
+    {
+        /// Fee of `uxt`, including its tip, in `asset`. `None` if the asset can not pay fees.
+        fn query_fee_in_asset(
+            uxt: <Block as sp_runtime::traits::Block>::Extrinsic,
+            len: u32,
+            asset: AssetId,
+        ) -> Option<Balance>;
+        /// Assets that can currently pay transaction fees.
+        fn supported_fee_assets() -> Vec<AssetId>;
+    }
 }
 
 pub const PALLET_NAMES: [(&str, &str); 6] = [
@@ -317,3 +1202,186 @@ pub const PALLET_NAMES: [(&str, &str); 6] = [
     ("AssetTxPayment", "pallet_asset_tx_payment"),
     ("OracleMembership", "pallet_membership"),
 ];
+
+pub const ASSET_CONVERSION_PALLET_NAMES: [(&str, &str); 6] = [
+    ("Assets", "pallet_assets"),
+    ("TransactionPayment", "pallet_transaction_payment"),
+    ("AssetManager", "pallet_asset_manager"),
+    ("PoolAssets", "pallet_assets<Instance2>"),
+    ("AssetConversion", "pallet_asset_conversion"),
+    ("AssetTxPayment", "pallet_asset_conversion_tx_payment"),
+];
+
+pub const FOREIGN_ASSETS_PALLET_NAMES: [(&str, &str); 1] =
+    [("ForeignAssets", "pallet_assets<Instance3>")];
+
+pub const ASSET_FEE_ACCOUNTING_PALLET_NAMES: [(&str, &str); 1] = [(
+    "AssetFeeAccounting",
+    "openzeppelin_pallet_abstractions::asset_fee_accounting",
+)];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // One asset unit is worth 10 native units, asset 0 has no price.
+    struct TenToOne;
+    impl ConversionToAssetBalance<u64, u32, u64> for TenToOne {
+        type Error = ();
+
+        fn to_asset_balance(balance: u64, asset_id: u32) -> Result<u64, ()> {
+            if asset_id == 0 {
+                return Err(());
+            }
+            Ok(balance / 10)
+        }
+    }
+
+    #[test]
+    fn convert_fee_converts_with_the_price() {
+        assert_eq!(convert_fee::<TenToOne, u64, u32, u64>(1_000, 1), Ok(100));
+    }
+
+    #[test]
+    fn convert_fee_charges_at_least_one_unit_for_non_zero_fees() {
+        assert_eq!(convert_fee::<TenToOne, u64, u32, u64>(5, 1), Ok(1));
+        assert_eq!(convert_fee::<TenToOne, u64, u32, u64>(0, 1), Ok(0));
+    }
+
+    #[test]
+    fn convert_fee_rejects_assets_without_price() {
+        assert_eq!(
+            convert_fee::<TenToOne, u64, u32, u64>(1_000, 0),
+            Err(InvalidTransaction::Payment.into())
+        );
+    }
+
+    #[test]
+    fn fee_totals_accumulate_payments() {
+        let fee = AssetFeePaid {
+            who: 1u64,
+            asset: 1u32,
+            native_fee: 1_000u64,
+            asset_fee: 100,
+            tip: 10,
+            refund: 5,
+            price: Some(FixedU128::from_u32(10)),
+        };
+        let mut totals = FeeTotals::default();
+        totals.note(&fee);
+        totals.note(&fee);
+        assert_eq!(
+            totals,
+            FeeTotals {
+                native_fees: 2_000,
+                asset_fees: 200,
+                tips: 20,
+                payments: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn fee_totals_saturate() {
+        let fee = AssetFeePaid {
+            who: 1u64,
+            asset: 1u32,
+            native_fee: u64::MAX,
+            asset_fee: 1,
+            tip: 0,
+            refund: 0,
+            price: None,
+        };
+        let mut totals = FeeTotals::default();
+        totals.note(&fee);
+        totals.note(&fee);
+        assert_eq!(totals.native_fees, u64::MAX);
+        assert_eq!(totals.asset_fees, 2);
+    }
+
+    #[test]
+    fn foreign_asset_locations() {
+        let self_para_id = ParaId::from(2000);
+        let is_foreign = |location: Location| is_foreign_asset_location(&location, self_para_id);
+
+        assert!(is_foreign(Location::new(
+            1,
+            [
+                Junction::Parachain(1000),
+                Junction::PalletInstance(50),
+                Junction::GeneralIndex(1984)
+            ]
+        )));
+        assert!(is_foreign(Location::new(
+            2,
+            [Junction::GlobalConsensus(
+                ::xcm::latest::NetworkId::Ethereum { chain_id: 1 }
+            )]
+        )));
+        // Local tokens, the relay chain token and locations under this parachain are kept elsewhere.
+        assert!(!is_foreign(Location::new(
+            0,
+            [Junction::PalletInstance(50)]
+        )));
+        assert!(!is_foreign(Location::parent()));
+        assert!(!is_foreign(Location::new(1, [Junction::Parachain(2000)])));
+        assert!(!is_foreign(Location::new(
+            1,
+            [Junction::Parachain(2000), Junction::PalletInstance(50)]
+        )));
+    }
+
+    thread_local! {
+        static PRICES: std::cell::RefCell<std::collections::BTreeMap<u32, u64>> =
+            std::cell::RefCell::new([(1, 10), (2, 20)].into());
+        static EVENTS: std::cell::RefCell<Vec<&'static str>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    struct Prices;
+    impl AssetPrices<u32> for Prices {
+        fn remove_price(id: &u32) {
+            PRICES.with(|prices| prices.borrow_mut().remove(id));
+        }
+    }
+
+    fn prices() -> Vec<(u32, u64)> {
+        PRICES.with(|prices| prices.borrow().clone().into_iter().collect())
+    }
+
+    #[test]
+    fn price_cleanup_removes_the_price_of_destroyed_assets() {
+        <PriceCleanup<Prices> as OnAssetLifecycle<u32, u64>>::on_created(&3, &0);
+        assert_eq!(prices(), vec![(1, 10), (2, 20)]);
+        <PriceCleanup<Prices> as OnAssetLifecycle<u32, u64>>::on_destroyed(&1);
+        assert_eq!(prices(), vec![(2, 20)]);
+    }
+
+    struct First;
+    impl OnAssetLifecycle<u32, u64> for First {
+        fn on_created(_id: &u32, _owner: &u64) {
+            EVENTS.with(|events| events.borrow_mut().push("first created"));
+        }
+
+        fn on_destroyed(_id: &u32) {
+            EVENTS.with(|events| events.borrow_mut().push("first destroyed"));
+        }
+    }
+
+    struct Second;
+    impl OnAssetLifecycle<u32, u64> for Second {
+        fn on_created(_id: &u32, _owner: &u64) {
+            EVENTS.with(|events| events.borrow_mut().push("second created"));
+        }
+    }
+
+    #[test]
+    fn lifecycle_hooks_run_in_order() {
+        <(First, Second, PriceCleanup<Prices>) as OnAssetLifecycle<u32, u64>>::on_created(&1, &0);
+        <(First, Second, PriceCleanup<Prices>) as OnAssetLifecycle<u32, u64>>::on_destroyed(&1);
+        assert_eq!(
+            EVENTS.with(|events| events.take()),
+            vec!["first created", "second created", "first destroyed"]
+        );
+        assert_eq!(prices(), vec![(2, 20)]);
+    }
+}
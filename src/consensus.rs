@@ -2,59 +2,206 @@
 //! - `pallet_authorship`
 //! - `pallet_aura`
 //! - `cumulus_pallet_aura_ext`
-//! - `pallet_collator_selection`
+//! - `pallet_collator_selection` or `pallet_collator_staking`, depending on the staking backend
 //! - `pallet_session`
 //!
 //! # Parameters
 //! - `$t`: A type that implements the `ConsensusConfig` trait, providing the necessary associated types
 //!   and configurations.
+//! - staking backend (optional): `CollatorSelection` (default) for invulnerables plus candidacy bonds, or
+//!   `CollatorStaking` for delegated proof-of-stake, where stakers back candidates and share their rewards.
+//!   Use [`PALLET_NAMES`] or [`STAKING_PALLET_NAMES`] (`Consensus` or `ConsensusStaking` in
+//!   `construct_openzeppelin_runtime`) accordingly.
+//!
+//! The `CollatorStaking` backend freezes staked funds. The default `SystemConfig::MaxFreezes` of 8 leaves
+//! room for its freeze reasons, runtimes overriding it must not set it to 0.
+//!
+//! Block production is selected with `ConsensusConfig::ConsensusMode` ([`Synchronous`], [`AsyncBacking`]
+//! or [`ElasticScaling`]). The macro generates the matching `ConsensusHook`, `CheckAssociatedRelayNumber`
+//! and `TimestampMinimumPeriod`, which must be passed to `SystemConfig::ConsensusHook`,
+//! `SystemConfig::CheckAssociatedRelayNumber` and `SystemConfig::SlotDuration`. They have no defaults there.
+//!
+//! Session length, offset, kick threshold and pot id are type-level settings of `ConsensusConfig`.
+//! Sessions last 6 hours of blocks of the selected mode by default ([`ModeSessionLength`]).
+//! The abstraction has no origin of its own for changing them, this is out of its scope. To let governance
+//! change them without a runtime upgrade, pass dynamic parameters (e.g. from `pallet_parameters`, with its
+//! admin origin) instead of constants. Candidacy bond, desired candidates and invulnerables are changed
+//! on-chain by `CollatorSelectionUpdateOrigin`. With the `CollatorSelection` backend, the generated
+//! `collator_selection_genesis` sets the initial candidacy bond to `ConsensusConfig::CandidacyBondMultiplier`
+//! times the existential deposit.
+//!
+//! Collators are rewarded from the pot. New issuance can be minted into it every block or session with
+//! `ConsensusConfig::BlockIssuance` and `ConsensusConfig::SessionIssuance`, split with the Treasury and the
+//! stakers and capped by `ConsensusConfig::MaxSupply`. See [`crate::rewards`]. With the `CollatorSelection`
+//! backend the staking pot is the collator pot, so the stakers' share goes to the collators.
 //!
 //! # Important
 //! Rerun benchmarks if making changes to runtime configuration, as weight calculations
 //! may need to be updated.
 
+use frame_support::traits::Get;
+
 #[macro_export]
 macro_rules! impl_openzeppelin_consensus {
-    ($t:ty) => {
+    // Pallets shared by every staking backend. `$backend` is the pallet managing the collator set and
+    // `$staking_pot_id` the pot its stakers are paid from.
+    (@common $t:ty, $backend:ident, $pallet:ident, $staking_pot_id:ident) => {
         impl pallet_authorship::Config for Runtime {
-            type EventHandler = (CollatorSelection,);
+            // Block rewards are minted before the backend pays the author from the pot.
+            type EventHandler = (
+                $crate::rewards::BlockRewards<ConsensusRewards, <$t as ConsensusConfig>::BlockIssuance>,
+                $backend,
+            );
             type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
         }
 
-        // Allow multiple blocks per slot based on the async backing feature.
-        #[cfg(not(feature = "async-backing"))]
+        // Allow multiple blocks per slot based on the consensus mode.
         parameter_types! {
-            pub const AllowMultipleBlocksPerSlot: bool = false;
+            pub const AllowMultipleBlocksPerSlot: bool =
+                <<$t as ConsensusConfig>::ConsensusMode as $crate::consensus::ConsensusMode>::ALLOW_MULTIPLE_BLOCKS_PER_SLOT;
         }
 
-        #[cfg(feature = "async-backing")]
+        // Consensus hook and relay number check matching the consensus mode, to be passed to
+        // `SystemConfig::ConsensusHook` and used by `AuraUnincludedSegmentApi`.
+        pub type ConsensusHook = cumulus_pallet_aura_ext::FixedVelocityConsensusHook<
+            Runtime,
+            { $crate::consensus::RELAY_CHAIN_SLOT_DURATION_MILLIS },
+            { <<$t as ConsensusConfig>::ConsensusMode as $crate::consensus::ConsensusMode>::BLOCK_PROCESSING_VELOCITY },
+            { <<$t as ConsensusConfig>::ConsensusMode as $crate::consensus::ConsensusMode>::UNINCLUDED_SEGMENT_CAPACITY },
+        >;
+        pub type CheckAssociatedRelayNumber =
+            <<$t as ConsensusConfig>::ConsensusMode as $crate::consensus::ConsensusMode>::CheckAssociatedRelayNumber;
+
+        // Checks that the slot duration of the consensus mode matches the chain, to be added to
+        // `SystemConfig::SingleBlockMigrations`.
+        pub type ConsensusMigrations =
+            $crate::migrations::v1::CheckSlotDuration<<$t as ConsensusConfig>::SlotDuration>;
+
         parameter_types! {
-            pub const AllowMultipleBlocksPerSlot: bool = true;
+            // The minimum period between blocks, to be passed to `SystemConfig::SlotDuration` so that
+            // `pallet_timestamp` agrees with the slot duration of `pallet_aura`. It is zero when multiple
+            // blocks can be authored within the same slot.
+            pub TimestampMinimumPeriod: u64 = if AllowMultipleBlocksPerSlot::get() {
+                0
+            } else {
+                <<$t as ConsensusConfig>::SlotDuration as frame_support::traits::Get<u64>>::get() / 2
+            };
         }
 
         // The Aura module extends Aura consensus by managing offline reporting.
         impl pallet_aura::Config for Runtime {
             type AllowMultipleBlocksPerSlot = AllowMultipleBlocksPerSlot;
-            // The identifier type for an authority.
-            type AuthorityId = AuraId;
+            // The identifier type for an authority. sr25519 by default, ed25519 can be used for collators
+            // whose keys are stored in HSMs.
+            type AuthorityId = <$t as ConsensusConfig>::AuraAuthorityId;
             // A way to check whether a given validator is disabled and should not be authoring blocks.
             type DisabledValidators = <$t as ConsensusConfig>::DisabledValidators;
             // Max number of authorities allowed
             type MaxAuthorities = <$t as ConsensusConfig>::MaxAuthorities;
-            // A slot duration provider which infers the slot duration from the [pallet_timestamp::Config::MinimumPeriod] by multiplying
-            // it by two, to ensure that authors have the majority of their slot to author within.
-            type SlotDuration = pallet_aura::MinimumPeriodTimesTwo<Self>;
+            // The slot duration Aura should run with. It is also exposed by `AuraApi::slot_duration`.
+            type SlotDuration = <$t as ConsensusConfig>::SlotDuration;
         }
 
         impl cumulus_pallet_aura_ext::Config for Runtime {}
 
         parameter_types! {
-            pub const PotId: PalletId = PalletId(*b"PotStake");
+            pub PotId: PalletId = <<$t as ConsensusConfig>::PotId as frame_support::traits::Get<PalletId>>::get();
             // A session is a period of time that has a constant set of validators. Validators can only join or
             // exit the validator set at a session change. It is measured in block numbers.
-            pub const SessionLength: BlockNumber = 6 * HOURS;
+            pub SessionLength: BlockNumber =
+                <<$t as ConsensusConfig>::SessionLength as frame_support::traits::Get<u32>>::get().into();
             // StakingAdmin pluralistic body.
-            pub const StakingAdminBodyId: BodyId = BodyId::Defense;
+            pub StakingAdminBodyId: BodyId =
+                <<$t as ConsensusConfig>::StakingAdminBodyId as frame_support::traits::Get<BodyId>>::get();
+        }
+
+        parameter_types! {
+            // pallet_session ends the session after a fixed period of blocks.
+            // The first session will have length of Offset,
+            // and the following sessions will have length of Period.
+            // With the default Offset of zero the chain starts processing blocks immediately,
+            // and then rotates parachain validators every `SessionLength` blocks.
+            pub Period: u32 = <<$t as ConsensusConfig>::SessionLength as frame_support::traits::Get<u32>>::get();
+            pub Offset: u32 = <<$t as ConsensusConfig>::SessionOffset as frame_support::traits::Get<u32>>::get();
+        }
+
+        // The Session pallet allows validators to manage their session keys, provides a function for
+        // changing the session length, and handles session rotation.
+        impl pallet_session::Config for Runtime {
+            // A session key is actually several keys kept together that provide the various
+            // signing functions required by network authorities/validators in pursuit of their duties.
+            type Keys = SessionKeys;
+            // Something that can predict the next session rotation
+            type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
+            type RuntimeEvent = RuntimeEvent;
+            // Handler when a session has changed
+            type SessionHandler =
+                <SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
+            // Handler for managing new session. Session rewards are minted before the backend ends the session.
+            type SessionManager = $crate::rewards::SessionRewards<
+                ConsensusRewards,
+                <$t as ConsensusConfig>::SessionIssuance,
+                $backend,
+            >;
+            // Indicator for when to end the session.
+            type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
+            // Every account has an associated validator ID. For some simple staking
+            // systems, this may just be the same as the account ID. For staking systems using a
+            // stash/controller model, the validator ID would be the stash account ID of the controller.
+            type ValidatorId = <Self as frame_system::Config>::AccountId;
+            // we don't have stash and controller, thus we don't need the convert as well.
+            type ValidatorIdOf = $pallet::IdentityCollator;
+            type WeightInfo = <$t as ConsensusWeight>::Session;
+        }
+
+        parameter_types! {
+            pub CollatorPotAccount: AccountId =
+                sp_runtime::traits::AccountIdConversion::<AccountId>::into_account_truncating(&PotId::get());
+            pub StakingPotAccount: AccountId =
+                sp_runtime::traits::AccountIdConversion::<AccountId>::into_account_truncating(&$staking_pot_id::get());
+        }
+
+        // Destinations and limits of the block and session rewards.
+        pub struct ConsensusRewards;
+        impl $crate::rewards::RewardsConfig for ConsensusRewards {
+            type AccountId = AccountId;
+            type CollatorPot = CollatorPotAccount;
+            type Currency = Balances;
+            type MaxSupply = <$t as ConsensusConfig>::MaxSupply;
+            type OnRewardsDistributed = <$t as ConsensusConfig>::OnRewardsDistributed;
+            type Runtime = Runtime;
+            type StakingPot = StakingPotAccount;
+            type StakingShare = <$t as ConsensusConfig>::StakingRewardShare;
+            type Treasury = <$t as ConsensusConfig>::RewardTreasury;
+            type TreasuryShare = <$t as ConsensusConfig>::TreasuryRewardShare;
+        }
+    };
+
+    ($t:ty) => {
+        $crate::impl_openzeppelin_consensus!($t, CollatorSelection);
+    };
+    ($t:ty, CollatorSelection) => {
+        $crate::impl_openzeppelin_consensus!(@common $t, CollatorSelection, pallet_collator_selection, PotId);
+
+        parameter_types! {
+            // Default bond for collator candidates, scaled by the existential deposit. It is set at genesis by
+            // `collator_selection_genesis` and changed later with `set_candidacy_bond` by
+            // `CollatorSelectionUpdateOrigin`.
+            pub CandidacyBond: Balance =
+                <<$t as ConsensusConfig>::CandidacyBondMultiplier as frame_support::traits::Get<u32>>::get() as Balance
+                    * <<Runtime as pallet_balances::Config>::ExistentialDeposit as frame_support::traits::Get<Balance>>::get();
+        }
+
+        // Genesis config of `pallet_collator_selection` with the default `CandidacyBond`, for the genesis presets
+        // of the runtime.
+        pub fn collator_selection_genesis(
+            invulnerables: sp_std::vec::Vec<AccountId>,
+        ) -> pallet_collator_selection::GenesisConfig<Runtime> {
+            pallet_collator_selection::GenesisConfig {
+                invulnerables,
+                candidacy_bond: CandidacyBond::get(),
+                ..Default::default()
+            }
         }
 
         // A pallet to manage collators in a parachain.
@@ -62,7 +209,7 @@ macro_rules! impl_openzeppelin_consensus {
             // The currency mechanism.
             type Currency = Balances;
             // should be a multiple of session or things will get inconsistent
-            type KickThreshold = Period;
+            type KickThreshold = <$t as ConsensusConfig>::KickThreshold;
             // Maximum number of candidates that we should have without taking into account the invulnerables.
             type MaxCandidates = <$t as ConsensusConfig>::MaxCandidates;
             // Maximum number of invulnerables (a set of collators appointed by governance. These accounts will always be collators.)
@@ -84,40 +231,170 @@ macro_rules! impl_openzeppelin_consensus {
             type ValidatorRegistration = Session;
             type WeightInfo = <$t as ConsensusWeight>::CollatorSelection;
         }
+    };
+    ($t:ty, CollatorStaking) => {
+        $crate::impl_openzeppelin_consensus!(@common $t, CollatorStaking, pallet_collator_staking, ExtraRewardPotId);
 
         parameter_types! {
-            // pallet_session ends the session after a fixed period of blocks.
-            // The first session will have length of Offset,
-            // and the following sessions will have length of Period.
-            // By setting Offset to zero we allow the chain to start processing blocks immediately.
-            // And then rotate parachain validators every 6 hours.
-            pub const Period: u32 = 6 * HOURS;
-            pub const Offset: u32 = 0;
+            pub ExtraRewardPotId: PalletId =
+                <<$t as ConsensusConfig>::ExtraRewardPotId as frame_support::traits::Get<PalletId>>::get();
+            // Delays are expressed in sessions by the configuration and converted to blocks here.
+            pub CandidateUnlockDelay: BlockNumber =
+                (<<$t as ConsensusConfig>::CandidateUnlockDelay as frame_support::traits::Get<u32>>::get()
+                    * <<$t as ConsensusConfig>::SessionLength as frame_support::traits::Get<u32>>::get())
+                    .into();
+            pub StakeUnlockDelay: BlockNumber =
+                (<<$t as ConsensusConfig>::StakeUnlockDelay as frame_support::traits::Get<u32>>::get()
+                    * <<$t as ConsensusConfig>::SessionLength as frame_support::traits::Get<u32>>::get())
+                    .into();
         }
 
-        // The Session pallet allows validators to manage their session keys, provides a function for
-        // changing the session length, and handles session rotation.
-        impl pallet_session::Config for Runtime {
-            // A session key is actually several keys kept together that provide the various
-            // signing functions required by network authorities/validators in pursuit of their duties.
-            type Keys = SessionKeys;
-            // Something that can predict the next session rotation
-            type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
+        // Delegated proof-of-stake collator selection. Candidates are ranked by their own bond plus the
+        // stake delegated to them, and rewards from the pot are shared with their stakers.
+        impl pallet_collator_staking::Config for Runtime {
+            // The currency mechanism. Bonds and stakes are frozen, not reserved.
+            type Currency = Balances;
+            // The overarching freeze reason.
+            type RuntimeFreezeReason = RuntimeFreezeReason;
+            // Origin that can dictate updating parameters of this pallet.
+            type UpdateOrigin = <$t as ConsensusConfig>::CollatorSelectionUpdateOrigin;
+            // Account Identifier from which the internal Pot is generated.
+            type PotId = PotId;
+            // Account Identifier of the pot funding rewards on top of the collected fees.
+            type ExtraRewardPotId = ExtraRewardPotId;
+            // Receiver of the extra reward pot leftovers. `None` burns them.
+            type ExtraRewardReceiver = <$t as ConsensusConfig>::ExtraRewardReceiver;
+            // Maximum number of candidates that we should have without taking into account the invulnerables.
+            type MaxCandidates = <$t as ConsensusConfig>::MaxCandidates;
+            // Minimum number eligible collators. Should always be greater than zero.
+            type MinEligibleCollators = <$t as ConsensusConfig>::MinEligibleCollators;
+            // Maximum number of invulnerables.
+            type MaxInvulnerables = <$t as ConsensusConfig>::MaxInvulnerables;
+            // should be a multiple of session or things will get inconsistent
+            type KickThreshold = <$t as ConsensusConfig>::KickThreshold;
+            // A stable ID for a collator.
+            type CollatorId = <Self as frame_system::Config>::AccountId;
+            // A conversion from account ID to collator ID.
+            type CollatorIdOf = pallet_collator_staking::IdentityCollator;
+            // Validate a user is registered
+            type CollatorRegistration = Session;
+            // Maximum number of candidates a single account can stake on.
+            type MaxStakedCandidates = <$t as ConsensusConfig>::MaxStakedCandidates;
+            // Maximum number of stakers a single candidate can have.
+            type MaxStakers = <$t as ConsensusConfig>::MaxStakers;
+            // Number of blocks a leaving candidate waits before its bond is released.
+            type BondUnlockDelay = CandidateUnlockDelay;
+            // Number of blocks a staker waits before an unstaked amount is released.
+            type StakeUnlockDelay = StakeUnlockDelay;
+            // The overarching event type.
+            type RuntimeEvent = RuntimeEvent;
+            type WeightInfo = <$t as ConsensusWeight>::CollatorStaking;
+        }
+    };
+    ($t:ty, $backend:ident) => {
+        compile_error!(concat!(
+            "Unknown staking backend `",
+            stringify!($backend),
+            "`. Available backends are CollatorSelection, CollatorStaking."
+        ));
+    };
+}
+
+/// Duration of a relay chain slot in milliseconds.
+pub const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32 = 6000;
+
+/// Block production mode of the parachain, selected with `ConsensusConfig::ConsensusMode`.
+///
+/// It defines the consensus hook, the Aura slot duration and the relay parent checks, so that they
+/// can not disagree with each other.
+pub trait ConsensusMode {
+    /// Whether multiple blocks can be authored within the same Aura slot.
+    const ALLOW_MULTIPLE_BLOCKS_PER_SLOT: bool;
+    /// Maximum number of parachain blocks authored per relay chain block.
+    const BLOCK_PROCESSING_VELOCITY: u32;
+    /// Maximum number of parachain blocks that can be authored ahead of their inclusion in the relay chain.
+    const UNINCLUDED_SEGMENT_CAPACITY: u32;
+    /// Duration of an Aura slot in milliseconds.
+    const SLOT_DURATION: u64;
+    /// Check of the relay parent number associated with each block.
+    type CheckAssociatedRelayNumber;
+}
+
+/// Synchronous backing: one block every two relay chain blocks, built on an included parent.
+pub struct Synchronous;
+impl ConsensusMode for Synchronous {
+    type CheckAssociatedRelayNumber = cumulus_pallet_parachain_system::RelayNumberStrictlyIncreases;
+
+    const ALLOW_MULTIPLE_BLOCKS_PER_SLOT: bool = false;
+    const BLOCK_PROCESSING_VELOCITY: u32 = 1;
+    const SLOT_DURATION: u64 = 2 * RELAY_CHAIN_SLOT_DURATION_MILLIS as u64;
+    const UNINCLUDED_SEGMENT_CAPACITY: u32 = 1;
+}
+
+/// Asynchronous backing: one block per relay chain block on a single core.
+pub struct AsyncBacking;
+impl ConsensusMode for AsyncBacking {
+    type CheckAssociatedRelayNumber =
+        cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
+
+    const ALLOW_MULTIPLE_BLOCKS_PER_SLOT: bool = true;
+    const BLOCK_PROCESSING_VELOCITY: u32 = 1;
+    const SLOT_DURATION: u64 = RELAY_CHAIN_SLOT_DURATION_MILLIS as u64;
+    const UNINCLUDED_SEGMENT_CAPACITY: u32 = 3;
+}
+
+/// Elastic scaling: `CORES` blocks per relay chain block, each of them backed on its own core.
+///
+/// `CORES` must be at least 1, which is checked at compile time.
+pub struct ElasticScaling<const CORES: u32>;
+impl<const CORES: u32> ConsensusMode for ElasticScaling<CORES> {
+    type CheckAssociatedRelayNumber =
+        cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
+
+    const ALLOW_MULTIPLE_BLOCKS_PER_SLOT: bool = true;
+    // Checked when the mode is used, since `ElasticScaling<0>` would never author a block.
+    const BLOCK_PROCESSING_VELOCITY: u32 = {
+        assert!(CORES > 0, "`ElasticScaling` requires at least one core");
+        CORES
+    };
+    const SLOT_DURATION: u64 = RELAY_CHAIN_SLOT_DURATION_MILLIS as u64;
+    const UNINCLUDED_SEGMENT_CAPACITY: u32 = 2 * Self::BLOCK_PROCESSING_VELOCITY + 1;
+}
+
+/// Slot duration of the given [`ConsensusMode`].
+pub struct ModeSlotDuration<M>(core::marker::PhantomData<M>);
+impl<M: ConsensusMode> Get<u64> for ModeSlotDuration<M> {
+    fn get() -> u64 {
+        M::SLOT_DURATION
+    }
+}
+
+/// Number of blocks authored in 6 hours with the given [`ConsensusMode`].
+pub struct ModeSessionLength<M>(core::marker::PhantomData<M>);
+impl<M: ConsensusMode> Get<u32> for ModeSessionLength<M> {
+    fn get() -> u32 {
+        (6 * 60 * 60 * 1000 * M::BLOCK_PROCESSING_VELOCITY as u64 / M::SLOT_DURATION) as u32
+    }
+}
+
+/// Number of blocks authored in a year of 365 days with the given [`ConsensusMode`].
+pub struct ModeYearLength<M>(core::marker::PhantomData<M>);
+impl<M: ConsensusMode> Get<u32> for ModeYearLength<M> {
+    fn get() -> u32 {
+        (365 * 24 * 60 * 60 * 1000 * M::BLOCK_PROCESSING_VELOCITY as u64 / M::SLOT_DURATION) as u32
+    }
+}
+
+/// Configures the [`crate::rewards`] pallet, which deposits a `RewardsDistributed` event for every reward
+/// minted by the Consensus abstraction. Pass it as `ConsensusConfig::OnRewardsDistributed`
+/// (`type OnRewardsDistributed = Rewards;`) and add it with [`REWARDS_PALLET_NAMES`] (`Rewards` in
+/// `construct_openzeppelin_runtime`).
+#[macro_export]
+macro_rules! impl_openzeppelin_rewards {
+    ($t:ty) => {
+        impl $crate::rewards::Config for Runtime {
+            type Balance = Balance;
             type RuntimeEvent = RuntimeEvent;
-            // Handler when a session has changed
-            type SessionHandler =
-                <SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
-            // Handler for managing new session.
-            type SessionManager = CollatorSelection;
-            // Indicator for when to end the session.
-            type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
-            // Every account has an associated validator ID. For some simple staking
-            // systems, this may just be the same as the account ID. For staking systems using a
-            // stash/controller model, the validator ID would be the stash account ID of the controller.
-            type ValidatorId = <Self as frame_system::Config>::AccountId;
-            // we don't have stash and controller, thus we don't need the convert as well.
-            type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
-            type WeightInfo = <$t as ConsensusWeight>::Session;
         }
     };
 }
@@ -129,3 +406,87 @@ pub const PALLET_NAMES: [(&str, &str); 5] = [
     ("CollatorSelection", "pallet_collator_selection"),
     ("Session", "pallet_session"),
 ];
+
+pub const STAKING_PALLET_NAMES: [(&str, &str); 5] = [
+    ("Authorship", "pallet_authorship"),
+    ("Aura", "pallet_aura"),
+    ("AuraExt", "cumulus_pallet_aura_ext"),
+    ("CollatorStaking", "pallet_collator_staking"),
+    ("Session", "pallet_session"),
+];
+
+pub const REWARDS_PALLET_NAMES: [(&str, &str); 1] =
+    [("Rewards", "openzeppelin_pallet_abstractions::rewards")];
+
+/// Kick threshold status of a collator candidate, returned by [`CollatorSelectionApi`].
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+    parity_scale_codec::Encode,
+    parity_scale_codec::Decode,
+    scale_info::TypeInfo,
+)]
+pub struct KickThresholdStatus<BlockNumber> {
+    /// The last block authored by the candidate, or the block it registered at.
+    pub last_authored_block: BlockNumber,
+    /// The block from which the candidate is kicked at the next session change if it still has not authored.
+    pub kick_threshold_block: BlockNumber,
+    /// Whether the candidate is past its kick threshold.
+    pub past_threshold: bool,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Queries of the `CollatorSelection` backend, for dashboards and node operators.
+    pub trait CollatorSelectionApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: parity_scale_codec::Codec,
+        Balance: parity_scale_codec::Codec,
+        BlockNumber: parity_scale_codec::Codec,
+    {
+        /// Collators appointed by governance.
+        fn invulnerables() -> sp_std::vec::Vec<AccountId>;
+        /// Candidates with their deposits, ordered by increasing deposit.
+        fn candidates() -> sp_std::vec::Vec<(AccountId, Balance)>;
+        /// Desired number of candidates, not counting the invulnerables.
+        fn desired_candidates() -> u32;
+        /// Current bond required to register as a candidate.
+        fn candidacy_bond() -> Balance;
+        /// Kick threshold status of `who`, or `None` if it is not a candidate.
+        fn kick_threshold_status(who: AccountId) -> Option<KickThresholdStatus<BlockNumber>>;
+        /// Collators of the next session.
+        fn next_session_collators() -> sp_std::vec::Vec<AccountId>;
+    }
+
+    /// Queries of the `CollatorStaking` backend, for staking dashboards and wallets.
+    pub trait CollatorStakingApi<AccountId, Balance>
+    where
+        AccountId: parity_scale_codec::Codec,
+        Balance: parity_scale_codec::Codec,
+    {
+        /// Candidates with their own bond and the total stake delegated to them.
+        fn candidates() -> sp_std::vec::Vec<(AccountId, Balance, Balance)>;
+        /// Candidates backed by `staker` and the amount staked on each of them.
+        fn delegations(staker: AccountId) -> sp_std::vec::Vec<(AccountId, Balance)>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_length_is_six_hours_of_blocks() {
+        assert_eq!(ModeSessionLength::<Synchronous>::get(), 1800);
+        assert_eq!(ModeSessionLength::<AsyncBacking>::get(), 3600);
+        assert_eq!(ModeSessionLength::<ElasticScaling<3>>::get(), 10800);
+    }
+
+    #[test]
+    fn year_length_is_a_year_of_blocks() {
+        assert_eq!(ModeYearLength::<Synchronous>::get(), 2_628_000);
+        assert_eq!(ModeYearLength::<AsyncBacking>::get(), 5_256_000);
+        assert_eq!(ModeYearLength::<ElasticScaling<3>>::get(), 15_768_000);
+    }
+}
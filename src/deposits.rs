@@ -0,0 +1,567 @@
+//! Pallet holding the deposits of `pallet_proxy` and `pallet_multisig` on `fungible` holds.
+//!
+//! In polkadot-stable2407 both pallets take their deposits through a `ReservableCurrency` and can not use a
+//! consideration like `pallet_preimage`. [`HoldCurrency`] implements `ReservableCurrency` on top of the holds
+//! of the native currency, with one [`HoldReason`] per pallet. Deposits therefore no longer share the
+//! anonymous reserves of the account, so `remove_proxies` and pure proxies can not release funds that were
+//! reserved by another pallet.
+//!
+//! Runtimes which already took deposits as reserves move them onto holds with
+//! [`UncheckedMigrateReservesToHolds`], run once by [`crate::migrations::v1`].
+
+pub use pallet::*;
+
+use frame_support::{
+    dispatch::DispatchResult,
+    traits::{
+        fungible::{InspectHold, MutateHold},
+        tokens::{Fortitude, Precision, Restriction},
+        BalanceStatus, Currency, ExistenceRequirement, Get, ReservableCurrency, SignedImbalance,
+        UncheckedOnRuntimeUpgrade, WithdrawReasons,
+    },
+    weights::Weight,
+};
+use parity_scale_codec::{Decode, Encode};
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    DispatchError,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+#[frame_support::pallet]
+pub mod pallet {
+    use frame_support::pallet_prelude::*;
+
+    use super::*;
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config:
+        frame_system::Config + pallet_proxy::Config + pallet_multisig::Config
+    {
+        type RuntimeHoldReason: From<HoldReason>;
+        /// Balance of the native currency.
+        type Balance: frame_support::traits::tokens::Balance;
+        /// The native currency holding the deposits.
+        type Currency: ReservableCurrency<Self::AccountId, Balance = Self::Balance>
+            + MutateHold<Self::AccountId, Balance = Self::Balance, Reason = Self::RuntimeHoldReason>;
+    }
+
+    /// Reasons for holding the deposits.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Deposit of the proxies and announcements of an account, including its pure proxies.
+        Proxy,
+        /// Deposit of a multisig operation.
+        Multisig,
+    }
+}
+
+/// `ReservableCurrency` reserving on the holds of `C` with the reason `R`.
+///
+/// Pass it as the `Currency` of pallets that only support reserves. All other operations are forwarded to
+/// `C`. Held deposits are slashed from the free balance after they are released, as holds can only be
+/// slashed as `fungible` credit.
+pub struct HoldCurrency<C, R>(PhantomData<(C, R)>);
+
+impl<A, C, R> Currency<A> for HoldCurrency<C, R>
+where
+    C: Currency<A> + MutateHold<A, Balance = <C as Currency<A>>::Balance>,
+    R: Get<<C as InspectHold<A>>::Reason>,
+{
+    type Balance = <C as Currency<A>>::Balance;
+    type NegativeImbalance = <C as Currency<A>>::NegativeImbalance;
+    type PositiveImbalance = <C as Currency<A>>::PositiveImbalance;
+
+    fn total_balance(who: &A) -> Self::Balance {
+        <C as Currency<A>>::total_balance(who)
+    }
+
+    fn can_slash(who: &A, value: Self::Balance) -> bool {
+        <C as Currency<A>>::can_slash(who, value)
+    }
+
+    fn total_issuance() -> Self::Balance {
+        <C as Currency<A>>::total_issuance()
+    }
+
+    fn active_issuance() -> Self::Balance {
+        <C as Currency<A>>::active_issuance()
+    }
+
+    fn deactivate(amount: Self::Balance) {
+        <C as Currency<A>>::deactivate(amount)
+    }
+
+    fn reactivate(amount: Self::Balance) {
+        <C as Currency<A>>::reactivate(amount)
+    }
+
+    fn minimum_balance() -> Self::Balance {
+        <C as Currency<A>>::minimum_balance()
+    }
+
+    fn burn(amount: Self::Balance) -> Self::PositiveImbalance {
+        <C as Currency<A>>::burn(amount)
+    }
+
+    fn issue(amount: Self::Balance) -> Self::NegativeImbalance {
+        <C as Currency<A>>::issue(amount)
+    }
+
+    fn free_balance(who: &A) -> Self::Balance {
+        <C as Currency<A>>::free_balance(who)
+    }
+
+    fn ensure_can_withdraw(
+        who: &A,
+        amount: Self::Balance,
+        reasons: WithdrawReasons,
+        new_balance: Self::Balance,
+    ) -> DispatchResult {
+        <C as Currency<A>>::ensure_can_withdraw(who, amount, reasons, new_balance)
+    }
+
+    fn transfer(
+        source: &A,
+        dest: &A,
+        value: Self::Balance,
+        existence_requirement: ExistenceRequirement,
+    ) -> DispatchResult {
+        <C as Currency<A>>::transfer(source, dest, value, existence_requirement)
+    }
+
+    fn slash(who: &A, value: Self::Balance) -> (Self::NegativeImbalance, Self::Balance) {
+        <C as Currency<A>>::slash(who, value)
+    }
+
+    fn deposit_into_existing(
+        who: &A,
+        value: Self::Balance,
+    ) -> Result<Self::PositiveImbalance, DispatchError> {
+        <C as Currency<A>>::deposit_into_existing(who, value)
+    }
+
+    fn deposit_creating(who: &A, value: Self::Balance) -> Self::PositiveImbalance {
+        <C as Currency<A>>::deposit_creating(who, value)
+    }
+
+    fn withdraw(
+        who: &A,
+        value: Self::Balance,
+        reasons: WithdrawReasons,
+        liveness: ExistenceRequirement,
+    ) -> Result<Self::NegativeImbalance, DispatchError> {
+        <C as Currency<A>>::withdraw(who, value, reasons, liveness)
+    }
+
+    fn make_free_balance_be(
+        who: &A,
+        balance: Self::Balance,
+    ) -> SignedImbalance<Self::Balance, Self::PositiveImbalance> {
+        <C as Currency<A>>::make_free_balance_be(who, balance)
+    }
+}
+
+impl<A, C, R> ReservableCurrency<A> for HoldCurrency<C, R>
+where
+    C: Currency<A> + MutateHold<A, Balance = <C as Currency<A>>::Balance>,
+    R: Get<<C as InspectHold<A>>::Reason>,
+{
+    fn can_reserve(who: &A, value: Self::Balance) -> bool {
+        C::can_hold(&R::get(), who, value)
+    }
+
+    fn slash_reserved(who: &A, value: Self::Balance) -> (Self::NegativeImbalance, Self::Balance) {
+        let released = C::release(&R::get(), who, value, Precision::BestEffort).unwrap_or_default();
+        let (imbalance, remaining) = <C as Currency<A>>::slash(who, released);
+        (
+            imbalance,
+            value.saturating_sub(released).saturating_add(remaining),
+        )
+    }
+
+    fn reserved_balance(who: &A) -> Self::Balance {
+        C::balance_on_hold(&R::get(), who)
+    }
+
+    fn reserve(who: &A, value: Self::Balance) -> DispatchResult {
+        C::hold(&R::get(), who, value)
+    }
+
+    fn unreserve(who: &A, value: Self::Balance) -> Self::Balance {
+        let released = C::release(&R::get(), who, value, Precision::BestEffort).unwrap_or_default();
+        value.saturating_sub(released)
+    }
+
+    fn repatriate_reserved(
+        slashed: &A,
+        beneficiary: &A,
+        value: Self::Balance,
+        status: BalanceStatus,
+    ) -> Result<Self::Balance, DispatchError> {
+        let mode = match status {
+            BalanceStatus::Free => Restriction::Free,
+            BalanceStatus::Reserved => Restriction::OnHold,
+        };
+        let moved = C::transfer_on_hold(
+            &R::get(),
+            slashed,
+            beneficiary,
+            value,
+            Precision::BestEffort,
+            mode,
+            Fortitude::Polite,
+        )?;
+        Ok(value.saturating_sub(moved))
+    }
+}
+
+type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+type BlockNumberOf<T> = frame_system::pallet_prelude::BlockNumberFor<T>;
+type CallHashOf<T> = <<T as pallet_proxy::Config>::CallHasher as sp_runtime::traits::Hash>::Output;
+
+/// Mirror of `pallet_multisig::Multisig`, whose fields are private.
+#[derive(Encode, Decode)]
+struct MultisigDeposit<BlockNumber, Balance, AccountId> {
+    _when: pallet_multisig::Timepoint<BlockNumber>,
+    deposit: Balance,
+    depositor: AccountId,
+    _approvals: Vec<AccountId>,
+}
+
+// The storage of `pallet_proxy` and `pallet_multisig` with the deposits decoded as the balance of the
+// native currency.
+#[frame_support::storage_alias]
+type Proxies<T: Config> = StorageMap<
+    Proxy,
+    frame_support::Twox64Concat,
+    AccountIdOf<T>,
+    (
+        Vec<
+            pallet_proxy::ProxyDefinition<
+                AccountIdOf<T>,
+                <T as pallet_proxy::Config>::ProxyType,
+                BlockNumberOf<T>,
+            >,
+        >,
+        <T as Config>::Balance,
+    ),
+>;
+
+#[frame_support::storage_alias]
+type Announcements<T: Config> = StorageMap<
+    Proxy,
+    frame_support::Twox64Concat,
+    AccountIdOf<T>,
+    (
+        Vec<pallet_proxy::Announcement<AccountIdOf<T>, CallHashOf<T>, BlockNumberOf<T>>>,
+        <T as Config>::Balance,
+    ),
+>;
+
+#[frame_support::storage_alias]
+type Multisigs<T: Config> = StorageDoubleMap<
+    Multisig,
+    frame_support::Twox64Concat,
+    AccountIdOf<T>,
+    frame_support::Blake2_128Concat,
+    [u8; 32],
+    MultisigDeposit<BlockNumberOf<T>, <T as Config>::Balance, AccountIdOf<T>>,
+>;
+
+/// Moves the reserved deposits of `pallet_proxy` and `pallet_multisig` onto holds.
+///
+/// Deposits that can not be held stay reserved and are logged.
+pub struct UncheckedMigrateReservesToHolds<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedMigrateReservesToHolds<T> {
+    /// Every deposit with the account it is taken from and its hold reason.
+    fn deposits() -> impl Iterator<Item = (AccountIdOf<T>, T::Balance, HoldReason)> {
+        Proxies::<T>::iter()
+            .map(|(who, (_, deposit))| (who, deposit, HoldReason::Proxy))
+            .chain(
+                Announcements::<T>::iter()
+                    .map(|(who, (_, deposit))| (who, deposit, HoldReason::Proxy)),
+            )
+            .chain(
+                Multisigs::<T>::iter_values()
+                    .map(|multisig| (multisig.depositor, multisig.deposit, HoldReason::Multisig)),
+            )
+    }
+
+    fn hold_reserved(who: &AccountIdOf<T>, deposit: T::Balance, reason: HoldReason) {
+        let missing = <T as Config>::Currency::unreserve(who, deposit);
+        let unreserved = deposit.saturating_sub(missing);
+        if let Err(error) = <T as Config>::Currency::hold(&reason.into(), who, unreserved) {
+            log::error!(
+                target: "runtime::deposits",
+                "Failed to hold the deposit of {:?}: {:?}",
+                who,
+                error
+            );
+            let _ = <T as Config>::Currency::reserve(who, unreserved);
+        }
+    }
+}
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateReservesToHolds<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut deposits = 0u64;
+        for (who, deposit, reason) in Self::deposits() {
+            deposits.saturating_inc();
+            if !deposit.is_zero() {
+                Self::hold_reserved(&who, deposit, reason);
+            }
+        }
+        // Reads the deposit and the account, writes the account and its holds.
+        <T as frame_system::Config>::DbWeight::get()
+            .reads_writes(deposits.saturating_mul(3), deposits.saturating_mul(2))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        let expected = Self::deposits().fold(
+            sp_std::collections::btree_map::BTreeMap::<_, T::Balance>::new(),
+            |mut expected, (who, deposit, reason)| {
+                let held = expected.entry((who, reason.encode())).or_default();
+                *held = held.saturating_add(deposit);
+                expected
+            },
+        );
+        Ok(expected.into_iter().collect::<Vec<_>>().encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let expected: Vec<((AccountIdOf<T>, Vec<u8>), T::Balance)> =
+            Decode::decode(&mut &state[..]).map_err(|_| "Failed to decode the deposits")?;
+        for ((who, reason), deposit) in expected {
+            let reason = HoldReason::decode(&mut &reason[..])
+                .map_err(|_| "Failed to decode the hold reason")?;
+            frame_support::ensure!(
+                <T as Config>::Currency::balance_on_hold(&reason.into(), &who) == deposit,
+                "Deposit was not moved onto a hold"
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use frame_support::{
+        assert_ok, derive_impl, parameter_types,
+        traits::{fungible::InspectHold, ConstU32, ConstU64, OnRuntimeUpgrade, StorageVersion},
+        weights::constants::RocksDbWeight,
+    };
+    use sp_runtime::{traits::BlakeTwo256, BuildStorage};
+
+    use super::*;
+
+    type Block = frame_system::mocking::MockBlock<Test>;
+
+    frame_support::construct_runtime!(
+        pub enum Test {
+            System: frame_system,
+            Balances: pallet_balances,
+            Proxy: pallet_proxy,
+            Multisig: pallet_multisig,
+            Deposits: crate::deposits,
+        }
+    );
+
+    #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+    impl frame_system::Config for Test {
+        type AccountData = pallet_balances::AccountData<u64>;
+        type Block = Block;
+        type DbWeight = RocksDbWeight;
+    }
+
+    #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+    impl pallet_balances::Config for Test {
+        type AccountStore = System;
+        type RuntimeHoldReason = RuntimeHoldReason;
+    }
+
+    parameter_types! {
+        pub const ProxyHoldReason: RuntimeHoldReason = RuntimeHoldReason::Deposits(HoldReason::Proxy);
+        pub const MultisigHoldReason: RuntimeHoldReason =
+            RuntimeHoldReason::Deposits(HoldReason::Multisig);
+    }
+
+    impl pallet_proxy::Config for Test {
+        type AnnouncementDepositBase = ConstU64<2>;
+        type AnnouncementDepositFactor = ConstU64<1>;
+        type CallHasher = BlakeTwo256;
+        type Currency = HoldCurrency<Balances, ProxyHoldReason>;
+        type MaxPending = ConstU32<2>;
+        type MaxProxies = ConstU32<4>;
+        type ProxyDepositBase = ConstU64<10>;
+        type ProxyDepositFactor = ConstU64<5>;
+        type ProxyType = ();
+        type RuntimeCall = RuntimeCall;
+        type RuntimeEvent = RuntimeEvent;
+        type WeightInfo = ();
+    }
+
+    impl pallet_multisig::Config for Test {
+        type Currency = HoldCurrency<Balances, MultisigHoldReason>;
+        type DepositBase = ConstU64<20>;
+        type DepositFactor = ConstU64<3>;
+        type MaxSignatories = ConstU32<4>;
+        type RuntimeCall = RuntimeCall;
+        type RuntimeEvent = RuntimeEvent;
+        type WeightInfo = ();
+    }
+
+    impl Config for Test {
+        type Balance = u64;
+        type Currency = Balances;
+        type RuntimeHoldReason = RuntimeHoldReason;
+    }
+
+    fn new_test_ext() -> sp_io::TestExternalities {
+        let mut storage = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap();
+        pallet_balances::GenesisConfig::<Test> {
+            balances: vec![(1, 1_000), (2, 1_000)],
+        }
+        .assimilate_storage(&mut storage)
+        .unwrap();
+        let mut ext: sp_io::TestExternalities = storage.into();
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+
+    fn held(reason: HoldReason, who: u64) -> u64 {
+        Balances::balance_on_hold(&reason.into(), &who)
+    }
+
+    #[test]
+    fn proxy_deposits_are_held() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, (), 0));
+            assert_eq!(held(HoldReason::Proxy, 1), 15);
+            assert_eq!(Balances::free_balance(1), 985);
+
+            assert_ok!(Proxy::remove_proxies(RuntimeOrigin::signed(1)));
+            assert_eq!(held(HoldReason::Proxy, 1), 0);
+            assert_eq!(Balances::free_balance(1), 1_000);
+        });
+    }
+
+    #[test]
+    fn removing_the_proxies_of_a_pure_proxy_does_not_release_other_deposits() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Proxy::create_pure(RuntimeOrigin::signed(1), (), 0, 0));
+            let pure = Proxy::pure_account(&1, &(), 0, None);
+            assert_eq!(held(HoldReason::Proxy, 1), 15);
+
+            // The pure proxy holds nothing itself, even when it has a multisig deposit elsewhere.
+            assert_ok!(Balances::transfer_allow_death(
+                RuntimeOrigin::signed(2),
+                pure,
+                500
+            ));
+            assert_ok!(Multisig::approve_as_multi(
+                RuntimeOrigin::signed(pure),
+                2,
+                vec![2],
+                None,
+                [0; 32],
+                Weight::zero()
+            ));
+            assert_ok!(Proxy::remove_proxies(RuntimeOrigin::signed(pure)));
+            assert_eq!(held(HoldReason::Multisig, pure), 26);
+            assert_eq!(held(HoldReason::Proxy, 1), 15);
+        });
+    }
+
+    #[test]
+    fn killing_a_pure_proxy_releases_the_deposit_of_its_spawner() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Proxy::create_pure(RuntimeOrigin::signed(1), (), 0, 0));
+            let pure = Proxy::pure_account(&1, &(), 0, None);
+
+            assert_ok!(Proxy::kill_pure(
+                RuntimeOrigin::signed(pure),
+                1,
+                (),
+                0,
+                1,
+                0
+            ));
+            assert_eq!(held(HoldReason::Proxy, 1), 0);
+            assert_eq!(Balances::free_balance(1), 1_000);
+        });
+    }
+
+    #[test]
+    fn multisig_deposits_are_held() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Multisig::approve_as_multi(
+                RuntimeOrigin::signed(1),
+                2,
+                vec![2],
+                None,
+                [0; 32],
+                Weight::zero()
+            ));
+            assert_eq!(held(HoldReason::Multisig, 1), 26);
+
+            assert_ok!(Multisig::cancel_as_multi(
+                RuntimeOrigin::signed(1),
+                2,
+                vec![2],
+                pallet_multisig::Pallet::<Test>::timepoint(),
+                [0; 32]
+            ));
+            assert_eq!(held(HoldReason::Multisig, 1), 0);
+            assert_eq!(Balances::free_balance(1), 1_000);
+        });
+    }
+
+    #[test]
+    fn reserved_deposits_are_moved_onto_holds_once() {
+        new_test_ext().execute_with(|| {
+            // State of a runtime which took the deposits as reserves.
+            StorageVersion::new(0).put::<Deposits>();
+            assert_ok!(<Balances as ReservableCurrency<u64>>::reserve(&1, 15 + 3));
+            assert_ok!(<Balances as ReservableCurrency<u64>>::reserve(&2, 26));
+            Proxies::<Test>::insert(1, (Vec::new(), 15));
+            Announcements::<Test>::insert(1, (Vec::new(), 3));
+            Multisigs::<Test>::insert(
+                2,
+                [0; 32],
+                MultisigDeposit {
+                    _when: pallet_multisig::Timepoint {
+                        height: 1,
+                        index: 0,
+                    },
+                    deposit: 26,
+                    depositor: 2,
+                    _approvals: vec![2],
+                },
+            );
+
+            let weight = crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+            assert_eq!(held(HoldReason::Proxy, 1), 18);
+            assert_eq!(held(HoldReason::Multisig, 2), 26);
+            assert_eq!(Balances::free_balance(1), 982);
+            assert_eq!(Balances::free_balance(2), 974);
+            assert_eq!(StorageVersion::get::<Deposits>(), 1);
+            assert!(weight.all_gte(RocksDbWeight::get().reads_writes(9, 6)));
+
+            crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+            assert_eq!(held(HoldReason::Proxy, 1), 18);
+            assert_eq!(held(HoldReason::Multisig, 2), 26);
+        });
+    }
+}
@@ -15,6 +15,11 @@
 //! Rerun benchmarks if making changes to runtime configuration, as weight calculations
 //! may need to be updated.
 
+use core::{fmt::Debug, marker::PhantomData};
+use sp_core::H160;
+use sp_runtime::traits::Convert;
+use sp_std::vec::Vec;
+
 #[macro_export]
 macro_rules! impl_openzeppelin_evm {
     ($t:ty) => {
@@ -128,9 +133,64 @@ macro_rules! impl_openzeppelin_evm {
             type Erc20TransferGasLimit = <$t as EvmConfig>::Erc20XcmBridgeTransferGasLimit;
             type EvmRunner = pallet_evm::runner::stack::Runner<Self>;
         }
+
+        /// Access of [`Erc20AssetRegistration`] to the accounts of `pallet_evm`.
+        pub struct PalletEvmAccounts;
+        impl $crate::evm::EvmAccounts for PalletEvmAccounts {
+            fn create_account(address: sp_core::H160, code: sp_std::vec::Vec<u8>) -> Result<(), impl core::fmt::Debug> {
+                pallet_evm::Pallet::<Runtime>::create_account(address, code)
+            }
+
+            fn remove_account(address: &sp_core::H160) {
+                pallet_evm::Pallet::<Runtime>::remove_account(address);
+            }
+        }
+
+        /// Asset lifecycle hook deploying the ERC-20 precompile code of created assets in `pallet_evm`, see
+        /// `openzeppelin_pallet_abstractions::evm::Erc20Registration`.
+        pub type Erc20AssetRegistration<AssetAddress> = $crate::evm::Erc20Registration<PalletEvmAccounts, AssetAddress>;
     };
 }
 
+/// Code deployed at the address of asset precompiles. Precompiles have no code, but contracts checking
+/// `extcodesize` before calling them (e.g. Solidity calls to an interface) would revert. The code is
+/// `PUSH1 0 PUSH1 0 REVERT`, it is never run as the precompile is called instead.
+pub const ASSET_PRECOMPILE_CODE: [u8; 5] = [0x60, 0x00, 0x60, 0x00, 0xfd];
+
+/// Accounts of the EVM, updated by [`Erc20Registration`]. Implemented for `pallet_evm` by the
+/// `PalletEvmAccounts` type generated by [`impl_openzeppelin_evm!`].
+pub trait EvmAccounts {
+    fn create_account(address: H160, code: Vec<u8>) -> Result<(), impl Debug>;
+    fn remove_account(address: &H160);
+}
+
+/// Asset lifecycle hook deploying [`ASSET_PRECOMPILE_CODE`] in `Accounts` at the ERC-20 precompile address of
+/// created assets, given by `AssetAddress`, and removing it when they are destroyed. Failures are logged.
+pub struct Erc20Registration<Accounts, AssetAddress>(PhantomData<(Accounts, AssetAddress)>);
+impl<AssetId, AccountId, Accounts, AssetAddress> crate::assets::OnAssetLifecycle<AssetId, AccountId>
+    for Erc20Registration<Accounts, AssetAddress>
+where
+    AssetId: Clone,
+    Accounts: EvmAccounts,
+    AssetAddress: Convert<AssetId, H160>,
+{
+    fn on_created(id: &AssetId, _owner: &AccountId) {
+        let address = AssetAddress::convert(id.clone());
+        if let Err(error) = Accounts::create_account(address, ASSET_PRECOMPILE_CODE.to_vec()) {
+            log::warn!(
+                target: "runtime::evm",
+                "ERC-20 precompile code could not be deployed at {:?}: {:?}",
+                address,
+                error,
+            );
+        }
+    }
+
+    fn on_destroyed(id: &AssetId) {
+        Accounts::remove_account(&AssetAddress::convert(id.clone()));
+    }
+}
+
 pub const PALLET_NAMES: [(&str, &str); 5] = [
     ("Ethereum", "pallet_ethereum"),
     ("EVM", "pallet_evm"),
@@ -138,3 +198,85 @@ pub const PALLET_NAMES: [(&str, &str); 5] = [
     ("EVMChainId", "pallet_evm_chain_id"),
     ("Erc20XcmBridge", "pallet_erc20_xcm_bridge"),
 ];
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::BTreeMap};
+
+    use frame_support::parameter_types;
+
+    use super::*;
+    use crate::{assets::OnAssetLifecycle, test_log};
+
+    thread_local! {
+        static CODE: RefCell<BTreeMap<H160, Vec<u8>>> = const { RefCell::new(BTreeMap::new()) };
+    }
+
+    parameter_types! {
+        pub static Failing: bool = false;
+    }
+
+    struct Accounts;
+    impl EvmAccounts for Accounts {
+        fn create_account(address: H160, code: Vec<u8>) -> Result<(), impl Debug> {
+            if Failing::get() {
+                return Err("failing");
+            }
+            CODE.with(|accounts| accounts.borrow_mut().insert(address, code));
+            Ok(())
+        }
+
+        fn remove_account(address: &H160) {
+            CODE.with(|accounts| accounts.borrow_mut().remove(address));
+        }
+    }
+
+    struct AssetAddress;
+    impl Convert<u32, H160> for AssetAddress {
+        fn convert(id: u32) -> H160 {
+            H160::from_low_u64_be(id.into())
+        }
+    }
+
+    type Registration = Erc20Registration<Accounts, AssetAddress>;
+
+    fn code() -> Vec<(H160, Vec<u8>)> {
+        CODE.with(|accounts| accounts.borrow().clone().into_iter().collect())
+    }
+
+    #[test]
+    fn created_assets_get_the_precompile_code() {
+        <Registration as OnAssetLifecycle<u32, u64>>::on_created(&1, &0);
+        assert_eq!(
+            code(),
+            vec![(AssetAddress::convert(1), ASSET_PRECOMPILE_CODE.to_vec())]
+        );
+    }
+
+    #[test]
+    fn destroyed_assets_lose_the_precompile_code() {
+        <Registration as OnAssetLifecycle<u32, u64>>::on_created(&1, &0);
+        <Registration as OnAssetLifecycle<u32, u64>>::on_created(&2, &0);
+        <Registration as OnAssetLifecycle<u32, u64>>::on_destroyed(&1);
+        assert_eq!(
+            code(),
+            vec![(AssetAddress::convert(2), ASSET_PRECOMPILE_CODE.to_vec())]
+        );
+    }
+
+    #[test]
+    fn failures_are_logged() {
+        Failing::set(true);
+        let logs = test_log::capture(|| {
+            <Registration as OnAssetLifecycle<u32, u64>>::on_created(&1, &0);
+        });
+        assert_eq!(
+            logs,
+            vec![format!(
+                "runtime::evm: ERC-20 precompile code could not be deployed at {:?}: \"failing\"",
+                AssetAddress::convert(1)
+            )]
+        );
+        assert!(code().is_empty());
+    }
+}
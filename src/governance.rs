@@ -16,6 +16,8 @@
 //! Rerun benchmarks if making changes to runtime configuration, as weight calculations
 //! may need to be updated.
 
+use core::cmp::Ordering;
+
 #[macro_export]
 macro_rules! impl_openzeppelin_governance {
     ($t:ty) => {
@@ -117,6 +119,48 @@ macro_rules! impl_openzeppelin_governance {
 
         impl pallet_custom_origins::Config for Runtime {}
 
+        // Compares origins for `pallet_scheduler` by the decision deposit of their referenda track, with `Root`
+        // above every other origin. It allows a higher-privilege track origin to cancel tasks scheduled by
+        // a lower one, see [`cmp_decision_deposits`]. Can be passed to `SystemConfig::SchedulerOriginPrivilegeCmp`.
+        pub struct TrackOriginPrivilegeCmp;
+        impl frame_support::traits::PrivilegeCmp<OriginCaller> for TrackOriginPrivilegeCmp {
+            fn cmp_privilege(
+                left: &OriginCaller,
+                right: &OriginCaller,
+            ) -> Option<core::cmp::Ordering> {
+                if left == right {
+                    return Some(core::cmp::Ordering::Equal);
+                }
+
+                match (left, right) {
+                    (OriginCaller::system(frame_system::RawOrigin::Root), _) => {
+                        Some(core::cmp::Ordering::Greater)
+                    }
+                    (_, OriginCaller::system(frame_system::RawOrigin::Root)) => {
+                        Some(core::cmp::Ordering::Less)
+                    }
+                    _ => {
+                        let decision_deposit = |origin: &OriginCaller| {
+                            let track = <tracks::TracksInfo as pallet_referenda::TracksInfo<
+                                Balance,
+                                BlockNumber,
+                            >>::track_for(origin)
+                            .ok()?;
+                            <tracks::TracksInfo as pallet_referenda::TracksInfo<
+                                Balance,
+                                BlockNumber,
+                            >>::info(track)
+                            .map(|info| info.decision_deposit)
+                        };
+                        $crate::governance::cmp_decision_deposits(
+                            decision_deposit(left),
+                            decision_deposit(right),
+                        )
+                    }
+                }
+            }
+        }
+
         parameter_types! {
             pub const MaxBalance: Balance = Balance::MAX;
         }
@@ -160,6 +204,19 @@ macro_rules! impl_openzeppelin_governance {
     };
 }
 
+/// Compares the privilege of two different origins by the decision deposit of their referenda tracks. Origins
+/// without a track, or whose tracks have the same decision deposit, are not comparable, so that neither of them
+/// can cancel or reschedule the tasks of the other.
+pub fn cmp_decision_deposits<Balance: Ord>(
+    left: Option<Balance>,
+    right: Option<Balance>,
+) -> Option<Ordering> {
+    match left?.cmp(&right?) {
+        Ordering::Equal => None,
+        ordering => Some(ordering),
+    }
+}
+
 pub const PALLET_NAMES: [(&str, &str); 6] = [
     ("Sudo", "pallet_sudo"),
     ("Treasury", "pallet_treasury"),
@@ -168,3 +225,32 @@ pub const PALLET_NAMES: [(&str, &str); 6] = [
     ("Origins", "pallet_custom_origins"),
     ("Referenda", "pallet_referenda"),
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn higher_decision_deposit_is_more_privileged() {
+        assert_eq!(
+            cmp_decision_deposits(Some(10), Some(1)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            cmp_decision_deposits(Some(1), Some(10)),
+            Some(Ordering::Less)
+        );
+    }
+
+    #[test]
+    fn equal_decision_deposits_are_not_comparable() {
+        assert_eq!(cmp_decision_deposits(Some(5), Some(5)), None);
+    }
+
+    #[test]
+    fn origins_without_track_are_not_comparable() {
+        assert_eq!(cmp_decision_deposits(None, Some(5)), None);
+        assert_eq!(cmp_decision_deposits(Some(5), None), None);
+        assert_eq!(cmp_decision_deposits::<u128>(None, None), None);
+    }
+}
@@ -1,16 +1,37 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![feature(associated_type_defaults)]
 
+pub mod asset_fee_accounting;
 pub mod assets;
 pub mod consensus;
+pub mod deposits;
 pub mod evm;
 pub mod governance;
+pub mod migrations;
+pub mod nfts;
+pub mod proxy;
+pub mod relay_state;
+pub mod rewards;
 pub mod system;
 pub mod tanssi;
+pub mod vesting;
 pub mod weights;
 pub mod xcm;
+use crate::consensus::{ConsensusMode, ModeSessionLength, ModeSlotDuration};
 pub use crate::weights::*;
-use frame_support::traits::{ConstU32, Get, OnTimestampSet};
+use ::xcm::latest::BodyId;
+use frame_support::{
+    parameter_types,
+    traits::{
+        ConstBool, ConstU32, ConstU8, EqualPrivilegeOnly, Everything, Get, Nothing, OnTimestampSet,
+        WithdrawReasons,
+    },
+    PalletId,
+};
+// Used by the macros, so that runtimes do not need to depend on `log`.
+#[doc(hidden)]
+pub use log;
+use sp_runtime::Perbill;
 use sp_version::RuntimeVersion;
 
 pub trait SystemConfig: SystemWeight {
@@ -19,19 +40,57 @@ pub trait SystemConfig: SystemWeight {
     type SS58Prefix;
     type Version: Get<RuntimeVersion>;
     type ExistentialDeposit;
+    // Only used by `impl_openzeppelin_currency_units!`
+    type TokenDecimals: ConstGet<u8> = ConstU8<12>;
+    type TokenSymbol: Get<Option<&'static str>> = ();
     type ScheduleOrigin;
     type PreimageOrigin;
     type ProxyType;
+    // Set to the `ConsensusHook`, `CheckAssociatedRelayNumber` and `TimestampMinimumPeriod` generated by
+    // `impl_openzeppelin_consensus!`, so that they follow `ConsensusConfig::ConsensusMode`.
     type ConsensusHook;
+    type CheckAssociatedRelayNumber;
+    type OnSystemEvent = ();
     type SlotDuration;
     type OnTimestampSet: OnTimestampSet<u64>;
     type MaxConsumers = ConstU32<16>;
     type MaxSignatories = ConstU32<100>;
     type MaxPendingProxies = ConstU32<32>;
     type MaxProxies = ConstU32<32>;
-    type MaxFreezes = ConstU32<0>;
+    type AllowPureProxies: Get<bool> = ConstBool<false>;
+    type CallFilter = Everything;
+    // Leaves room for the freeze reasons of the pallets using freezes, e.g. `pallet_collator_staking`.
+    type MaxFreezes = ConstU32<8>;
     type MaxLocks = ConstU32<50>;
     type MaxReserves = ConstU32<50>;
+    type MaxScheduledPerBlock = ConstU32<50>;
+    type SchedulerMaximumWeightPercent: Get<u32> = ConstU32<80>;
+    type SchedulerOriginPrivilegeCmp = EqualPrivilegeOnly;
+    // Runs after the migrations of the abstractions, see `migrations::AbstractionMigrations`
+    type SingleBlockMigrations = ();
+    // Only used with the `MultiBlockMigrations` mode of `impl_openzeppelin_system!`
+    type MultiBlockMigrations = ();
+}
+
+/// A type-level constant which, unlike [`Get`], can be read in `const` contexts.
+pub trait ConstGet<T> {
+    const VALUE: T;
+}
+
+impl<const N: u8> ConstGet<u8> for ConstU8<N> {
+    const VALUE: u8 = N;
+}
+
+impl<const N: u32> ConstGet<u32> for ConstU32<N> {
+    const VALUE: u32 = N;
+}
+
+parameter_types! {
+    pub const DefaultPotId: PalletId = PalletId(*b"PotStake");
+    pub const DefaultExtraRewardPotId: PalletId = PalletId(*b"ExtraPot");
+    pub const DefaultStakingAdminBodyId: BodyId = BodyId::Defense;
+    pub DefaultUnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+        WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
 }
 
 pub trait ConsensusConfig: ConsensusWeight {
@@ -41,6 +100,34 @@ pub trait ConsensusConfig: ConsensusWeight {
     type MaxInvulnerables = ConstU32<20>;
     type MinEligibleCollators = ConstU32<4>;
     type CollatorSelectionUpdateOrigin;
+    type SessionLength: Get<u32> = ModeSessionLength<Self::ConsensusMode>;
+    type SessionOffset: Get<u32> = ConstU32<0>;
+    type KickThreshold: Get<u32> = Self::SessionLength;
+    type PotId: Get<PalletId> = DefaultPotId;
+    type StakingAdminBodyId: Get<BodyId> = DefaultStakingAdminBodyId;
+    // Only used by the `CollatorSelection` backend, for the candidacy bond at genesis
+    type CandidacyBondMultiplier: Get<u32> = ConstU32<16>;
+    // No default, the slot duration of a live chain must never change with an upgrade of the abstractions
+    type ConsensusMode: ConsensusMode;
+    type SlotDuration: Get<u64> = ModeSlotDuration<Self::ConsensusMode>;
+    type AuraAuthorityId = sp_consensus_aura::sr25519::AuthorityId;
+    // Only used by the `CollatorStaking` backend
+    type MaxStakedCandidates = ConstU32<16>;
+    type MaxStakers = ConstU32<200>;
+    type CandidateUnlockDelay: Get<u32> = ConstU32<1>;
+    type StakeUnlockDelay: Get<u32> = ConstU32<1>;
+    type ExtraRewardPotId: Get<PalletId> = DefaultExtraRewardPotId;
+    type ExtraRewardReceiver = ();
+    // Collator rewards, no issuance by default
+    type BlockIssuance = ();
+    type SessionIssuance = ();
+    type MaxSupply = ();
+    type TreasuryRewardShare: Get<Perbill> = ();
+    // Paid to the collator pot with the `CollatorSelection` backend, which has no stakers
+    type StakingRewardShare: Get<Perbill> = ();
+    type RewardTreasury = ();
+    // E.g. the `Rewards` pallet, to deposit an event for every reward
+    type OnRewardsDistributed = ();
 }
 
 pub trait AssetsConfig: AssetsWeight {
@@ -62,6 +149,25 @@ pub trait AssetsConfig: AssetsWeight {
     type FungiblesToAccount;
     type RootOperatorAccountId;
     type AssetsToBlockAuthor;
+    type FeeAssetId = ::xcm::v3::MultiLocation;
+    // Only called by the `Oracle` fee payment mode, e.g. the `AssetFeeAccounting` pallet
+    type OnAssetFeePaid = ();
+    type AssetLifecycleHooks = ();
+    // Implementations of `pallet_assets::FrozenBalance` for every instance. `pallet_assets_freezer` is only
+    // available from polkadot-stable2409, so nothing is frozen by default.
+    type Freezer = ();
+    // Only used by `impl_openzeppelin_foreign_assets!`
+    type ForeignAssetsStringLimit = ConstU32<50>;
+    type ForeignAssetLifecycleHooks = ();
+    type ForeignAssetsFreezer = ();
+    // Only used by the `AssetConversion` fee payment mode. No default: `pallet_asset_conversion` lets any
+    // signed account create a pool, this fee is the only limit on it
+    type PoolSetupFee;
+    type PoolSetupFeeTarget = ();
+    type PoolAssetsFreezer = ();
+    type LPFee = ConstU32<3>;
+    type LiquidityWithdrawalFee = ();
+    type MaxSwapPathLength = ConstU32<3>;
 }
 
 pub trait GovernanceConfig: GovernanceWeight {
@@ -146,9 +252,79 @@ pub trait EvmConfig: EvmWeight {
     type LocationToH160;
 }
 
+pub trait NftsConfig: NftsWeight {
+    type CollectionId = u32;
+    type ItemId = u32;
+    type CreateOrigin;
+    type ForceOrigin;
+    type Locker = ();
+    type StringLimit = ConstU32<256>;
+    type KeyLimit = ConstU32<64>;
+    type ValueLimit = ConstU32<256>;
+    type ApprovalsLimit = ConstU32<20>;
+    type ItemAttributesApprovalsLimit = ConstU32<30>;
+    type MaxTips = ConstU32<10>;
+    // In blocks, e.g. `ModeYearLength<ConsensusMode>`. No default, as it depends on the consensus mode
+    type MaxDeadlineDuration;
+    type MaxAttributesPerCall = ConstU32<10>;
+    type TradingEnabled: Get<bool> = ConstBool<true>;
+    type AttributesEnabled: Get<bool> = ConstBool<true>;
+    type ApprovalsEnabled: Get<bool> = ConstBool<true>;
+    type SwapsEnabled: Get<bool> = ConstBool<true>;
+}
+
+pub trait VestingConfig: VestingWeight {
+    type MinVestedTransfer;
+    type UnvestedFundsAllowedWithdrawReasons: Get<WithdrawReasons> =
+        DefaultUnvestedFundsAllowedWithdrawReasons;
+    type MaxVestingSchedules: ConstGet<u32> = ConstU32<28>;
+}
+
 pub trait TanssiConfig: TanssiWeight {
-    type AuthorInherent;
-    type AuthoritiesNothing;
+    type SlotBeacon;
+    type CanAuthor;
+    type AccountLookup;
+    // Only used with the `MaintenanceMode` mode of `impl_openzeppelin_tanssi!`
+    type MaintenanceOrigin = tanssi::EnsureRootOrigin;
+    type MaintenanceAllowedCalls = Nothing;
+    type XcmExecutionManager = ();
+}
+
+/// Captures the logs of the current thread, for the hooks that can only log their failures.
+#[cfg(test)]
+mod test_log {
+    use std::{cell::RefCell, string::String, vec::Vec};
+
+    thread_local! {
+        static LOGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    struct Logger;
+    impl log::Log for Logger {
+        fn enabled(&self, _metadata: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            LOGS.with(|logs| {
+                logs.borrow_mut()
+                    .push(std::format!("{}: {}", record.target(), record.args()))
+            });
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: Logger = Logger;
+
+    /// Runs `f` and returns the logs it emitted, as `target: message`.
+    pub(crate) fn capture(f: impl FnOnce()) -> Vec<String> {
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(log::LevelFilter::Trace);
+        LOGS.with(|logs| logs.borrow_mut().clear());
+        f();
+        LOGS.with(|logs| logs.take())
+    }
 }
 
 #[test]
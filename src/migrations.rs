@@ -0,0 +1,127 @@
+//! Migrations for the defaults of the abstractions that change between releases.
+//!
+//! `impl_openzeppelin_system!` runs [`AbstractionMigrations`] before `SystemConfig::SingleBlockMigrations`,
+//! so runtimes only list their own migrations there. The migrations are versioned by the storage version of
+//! the `Deposits` pallet, which every runtime using the System abstraction has, and do nothing on chains that
+//! start with the current release.
+//!
+//! Most defaults do not change storage. Their migrations are no-ops which check under `try-runtime` that the
+//! chain can take the new default:
+//! - v1:
+//!   - The deposits of `pallet_proxy` and `pallet_multisig` are moved from reserves onto holds.
+//!   - `pallet_balances::FreezeIdentifier` is `RuntimeFreezeReason` instead of `()` and `MaxFreezes` is 8
+//!     instead of 0. No account can have freezes, which would no longer decode.
+//!   - `ProxyType` has fixed codec indices. The proxies on chain must decode with them.
+//!   - The session length and kick threshold are derived from the consensus mode. They are not stored, so
+//!     the next session just starts with the new length.
+//!
+//! The slot duration of Aura is derived from the consensus mode as well. It can not change on a live chain,
+//! so the Consensus abstraction generates `ConsensusMigrations` with [`v1::CheckSlotDuration`], to be added to
+//! `SystemConfig::SingleBlockMigrations`.
+
+use frame_support::{
+    traits::{Get, OnRuntimeUpgrade, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+};
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::prelude::*;
+
+use crate::deposits;
+
+/// The migrations of all releases, run by `impl_openzeppelin_system!`.
+pub type AbstractionMigrations<T> = (v1::MigrateToV1<T>,);
+
+pub mod v1 {
+    use super::*;
+
+    /// Migrates from the defaults of the abstractions before versioned migrations were introduced.
+    pub type MigrateToV1<T> = frame_support::migrations::VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateToV1<T>,
+        deposits::Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    pub struct UncheckedMigrateToV1<T>(PhantomData<T>);
+
+    impl<T: deposits::Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            deposits::UncheckedMigrateReservesToHolds::<T>::on_runtime_upgrade()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let freezes = frame_support::storage::storage_prefix(b"Balances", b"Freezes");
+            frame_support::ensure!(
+                !frame_support::storage::unhashed::contains_prefixed_key(&freezes),
+                "Freezes of `FreezeIdentifier = ()` do not decode as `RuntimeFreezeReason`"
+            );
+            deposits::UncheckedMigrateReservesToHolds::<T>::pre_upgrade()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            for who in pallet_proxy::Proxies::<T>::iter_keys() {
+                frame_support::ensure!(
+                    pallet_proxy::Proxies::<T>::try_get(&who).is_ok(),
+                    "Proxies do not decode with the codec indices of `ProxyType`"
+                );
+            }
+            deposits::UncheckedMigrateReservesToHolds::<T>::post_upgrade(state)
+        }
+    }
+
+    // The slot of Aura and the time of `pallet_timestamp`, as stored by the Consensus abstraction.
+    #[cfg(feature = "try-runtime")]
+    #[frame_support::storage_alias]
+    pub(super) type CurrentSlot = StorageValue<Aura, u64>;
+    #[cfg(feature = "try-runtime")]
+    #[frame_support::storage_alias]
+    pub(super) type Now = StorageValue<Timestamp, u64>;
+
+    /// Checks under `try-runtime` that the current slot of Aura matches `SlotDuration`. Aura panics on the
+    /// next block if the slot duration of a live chain changes.
+    pub struct CheckSlotDuration<SlotDuration>(PhantomData<SlotDuration>);
+
+    impl<SlotDuration: Get<u64>> OnRuntimeUpgrade for CheckSlotDuration<SlotDuration> {
+        fn on_runtime_upgrade() -> Weight {
+            Weight::zero()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            if let (Some(slot), Some(now)) = (CurrentSlot::get(), Now::get()) {
+                frame_support::ensure!(
+                    now / SlotDuration::get().max(1) == slot,
+                    "`ConsensusConfig::SlotDuration` does not match the current slot of Aura"
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(all(test, feature = "try-runtime"))]
+mod tests {
+    use frame_support::{parameter_types, traits::OnRuntimeUpgrade};
+
+    use super::v1::{CheckSlotDuration, CurrentSlot, Now};
+
+    parameter_types! {
+        pub const SlotDuration: u64 = 6_000;
+    }
+
+    #[test]
+    fn slot_duration_must_match_the_current_slot() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            CurrentSlot::put(100);
+            Now::put(600_000);
+            assert!(CheckSlotDuration::<SlotDuration>::post_upgrade(Vec::new()).is_ok());
+
+            Now::put(1_200_000);
+            assert!(CheckSlotDuration::<SlotDuration>::post_upgrade(Vec::new()).is_err());
+        });
+    }
+}
@@ -0,0 +1,205 @@
+//! Implements the OpenZeppelin NFT configuration for a Runtime.
+//!
+//! This macro sets up the necessary configurations for the following pallets:
+//! - `pallet_nfts`
+//!
+//! # Parameters
+//! - `$t`: A type that implements the `NftsConfig` trait, providing the necessary associated types
+//!   and configurations.
+//!
+//! Deposits are derived from the storage they take with the runtime `deposit` function, like the
+//! metadata deposits of the Assets abstraction. All features of `pallet_nfts` are enabled by default
+//! and can be switched off with `NftsConfig::TradingEnabled`, `AttributesEnabled`, `ApprovalsEnabled`
+//! and `SwapsEnabled`.
+//!
+//! `NftsConfig::MaxDeadlineDuration` is in blocks and has no default, as their duration depends on the
+//! consensus mode. [`ModeYearLength`](crate::consensus::ModeYearLength) gives a year of blocks of a mode.
+//!
+//! # Important
+//! Rerun benchmarks if making changes to runtime configuration, as weight calculations
+//! may need to be updated.
+
+use frame_support::traits::Get;
+
+use crate::NftsConfig;
+
+#[macro_export]
+macro_rules! impl_openzeppelin_nfts {
+    ($t:ty) => {
+        parameter_types! {
+            // The basic amount of funds that must be reserved for a collection.
+            pub const NftsCollectionDeposit: Balance = deposit(1, 130);
+            // The basic amount of funds that must be reserved for an item.
+            pub const NftsItemDeposit: Balance = deposit(1, 164);
+            // The basic amount of funds that must be reserved when adding metadata to a collection or an item.
+            pub const NftsMetadataDepositBase: Balance = deposit(1, 129);
+            // The basic amount of funds that must be reserved when adding an attribute to an item.
+            pub const NftsAttributeDepositBase: Balance = deposit(1, 0);
+            // The additional funds that must be reserved for the number of bytes stored in metadata or attributes.
+            pub const NftsDepositPerByte: Balance = deposit(0, 1);
+            pub NftsFeatures: pallet_nfts::PalletFeatures = pallet_nfts::PalletFeatures::from_disabled(
+                $crate::nfts::disabled_features::<$t, _, _>([
+                    pallet_nfts::PalletFeature::Trading,
+                    pallet_nfts::PalletFeature::Attributes,
+                    pallet_nfts::PalletFeature::Approvals,
+                    pallet_nfts::PalletFeature::Swaps,
+                ]),
+            );
+        }
+
+        impl pallet_nfts::Config for Runtime {
+            // The maximum number of approvals an item could have.
+            type ApprovalsLimit = <$t as NftsConfig>::ApprovalsLimit;
+            type AttributeDepositBase = NftsAttributeDepositBase;
+            type CollectionDeposit = NftsCollectionDeposit;
+            // Identifier for the collection of item.
+            type CollectionId = <$t as NftsConfig>::CollectionId;
+            // Standard collection creation is only allowed if the origin attempting it and the
+            // collection are in this set.
+            type CreateOrigin = <$t as NftsConfig>::CreateOrigin;
+            type Currency = Balances;
+            type DepositPerByte = NftsDepositPerByte;
+            // Disables some of pallet's features.
+            type Features = NftsFeatures;
+            // The origin which may forcibly create or destroy an item or otherwise alter privileged
+            // attributes.
+            type ForceOrigin = <$t as NftsConfig>::ForceOrigin;
+            #[cfg(feature = "runtime-benchmarks")]
+            type Helper = ();
+            type ItemAttributesApprovalsLimit = <$t as NftsConfig>::ItemAttributesApprovalsLimit;
+            type ItemDeposit = NftsItemDeposit;
+            // The type used to identify a unique item within a collection.
+            type ItemId = <$t as NftsConfig>::ItemId;
+            // The maximum length of an attribute key.
+            type KeyLimit = <$t as NftsConfig>::KeyLimit;
+            // Locker trait to enable locking items from being transferred.
+            type Locker = <$t as NftsConfig>::Locker;
+            // The max number of attributes a user could set per call.
+            type MaxAttributesPerCall = <$t as NftsConfig>::MaxAttributesPerCall;
+            // The max duration in blocks for deadlines.
+            type MaxDeadlineDuration = <$t as NftsConfig>::MaxDeadlineDuration;
+            // The max number of tips a user could send.
+            type MaxTips = <$t as NftsConfig>::MaxTips;
+            type MetadataDepositBase = NftsMetadataDepositBase;
+            // Off-chain public key, used to verify the pre-signed mints and attributes.
+            type OffchainPublic = <Signature as sp_runtime::traits::Verify>::Signer;
+            // Off-chain signature type.
+            type OffchainSignature = Signature;
+            // The overarching event type.
+            type RuntimeEvent = RuntimeEvent;
+            // The maximum length of data stored on-chain.
+            type StringLimit = <$t as NftsConfig>::StringLimit;
+            // The maximum length of an attribute value.
+            type ValueLimit = <$t as NftsConfig>::ValueLimit;
+            type WeightInfo = <$t as NftsWeight>::Nfts;
+        }
+    };
+}
+
+/// Flags of the `pallet_nfts` features switched off in `T`, for `PalletFeatures::from_disabled`. `features`
+/// are the `Trading`, `Attributes`, `Approvals` and `Swaps` variants of `PalletFeature`, in this order.
+pub fn disabled_features<T: NftsConfig, Flags, Feature>(features: [Feature; 4]) -> Flags
+where
+    Flags: Default + core::ops::BitOrAssign<Feature>,
+{
+    let [trading, attributes, approvals, swaps] = features;
+    let mut disabled = Flags::default();
+    for (feature, enabled) in [
+        (trading, T::TradingEnabled::get()),
+        (attributes, T::AttributesEnabled::get()),
+        (approvals, T::ApprovalsEnabled::get()),
+        (swaps, T::SwapsEnabled::get()),
+    ] {
+        if !enabled {
+            disabled |= feature;
+        }
+    }
+    disabled
+}
+
+pub const PALLET_NAMES: [(&str, &str); 1] = [("Nfts", "pallet_nfts")];
+
+#[cfg(test)]
+mod tests {
+    use frame_support::{parameter_types, traits::ConstBool};
+
+    use super::*;
+    use crate::NftsWeight;
+
+    const TRADING: u8 = 1;
+    const ATTRIBUTES: u8 = 2;
+    const APPROVALS: u8 = 4;
+    const SWAPS: u8 = 8;
+
+    parameter_types! {
+        pub static Trading: bool = true;
+        pub static Attributes: bool = true;
+        pub static Approvals: bool = true;
+        pub static Swaps: bool = true;
+    }
+
+    struct Nfts;
+    impl NftsWeight for Nfts {}
+    impl NftsConfig for Nfts {
+        type ApprovalsEnabled = Approvals;
+        type AttributesEnabled = Attributes;
+        type CreateOrigin = ();
+        type ForceOrigin = ();
+        type MaxDeadlineDuration = ();
+        type SwapsEnabled = Swaps;
+        type TradingEnabled = Trading;
+    }
+
+    // All features are enabled by default.
+    struct DefaultNfts;
+    impl NftsWeight for DefaultNfts {}
+    impl NftsConfig for DefaultNfts {
+        type CreateOrigin = ();
+        type ForceOrigin = ();
+        type MaxDeadlineDuration = ();
+    }
+
+    // Swaps are switched off with a constant, as in the README.
+    struct NoSwaps;
+    impl NftsWeight for NoSwaps {}
+    impl NftsConfig for NoSwaps {
+        type CreateOrigin = ();
+        type ForceOrigin = ();
+        type MaxDeadlineDuration = ();
+        type SwapsEnabled = ConstBool<false>;
+    }
+
+    fn disabled<T: NftsConfig>() -> u8 {
+        disabled_features::<T, u8, u8>([TRADING, ATTRIBUTES, APPROVALS, SWAPS])
+    }
+
+    #[test]
+    fn all_features_are_enabled_by_default() {
+        assert_eq!(disabled::<DefaultNfts>(), 0);
+        assert_eq!(disabled::<Nfts>(), 0);
+    }
+
+    #[test]
+    fn every_flag_disables_its_feature() {
+        Trading::set(false);
+        assert_eq!(disabled::<Nfts>(), TRADING);
+        Trading::set(true);
+        Attributes::set(false);
+        assert_eq!(disabled::<Nfts>(), ATTRIBUTES);
+        Attributes::set(true);
+        Approvals::set(false);
+        assert_eq!(disabled::<Nfts>(), APPROVALS);
+        Approvals::set(true);
+        Swaps::set(false);
+        assert_eq!(disabled::<Nfts>(), SWAPS);
+        assert_eq!(disabled::<NoSwaps>(), SWAPS);
+    }
+
+    #[test]
+    fn disabled_features_are_combined() {
+        Trading::set(false);
+        Approvals::set(false);
+        Swaps::set(false);
+        assert_eq!(disabled::<Nfts>(), TRADING | APPROVALS | SWAPS);
+    }
+}
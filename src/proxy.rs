@@ -0,0 +1,505 @@
+//! Implements the OpenZeppelin proxy type for a Runtime.
+//!
+//! This macro generates a `ProxyType` enum together with its
+//! `InstanceFilter<RuntimeCall>` implementation, to be passed to `SystemConfig::ProxyType`.
+//!
+//! The following variants are always present:
+//! - `Any` -- allows all calls.
+//! - `NonTransfer` -- allows all calls that can not move funds out of the proxied account.
+//! - `CancelProxy` -- allows rejecting announcements made by other proxies.
+//!
+//! The following variants are added when the matching grouping is listed in `groupings`:
+//! - `Governance` -- `pallet_referenda`, `pallet_conviction_voting`, `pallet_whitelist` and `pallet_treasury` calls.
+//! - `Collator` -- `pallet_collator_selection` and `pallet_session` calls.
+//! - `AssetManagement` -- `pallet_assets` calls of asset owners, issuers, admins and freezers, without transfers.
+//! - `XcmTransfer` -- `pallet_xcm` and `orml_xtokens` asset transfer calls.
+//! - `Vesting` -- `pallet_vesting` calls unlocking vested funds, without vested transfers.
+//!
+//! # Parameters
+//! - `groupings`: A list of the grouping variants to generate. Only list groupings that are
+//!   present in the runtime, as each of them refers to the calls of its pallets.
+//! - `custom`: An optional list of runtime specific variants in the form of
+//!   `Variant = codec_index => |call| filter_expression`. Codec indices `0..=31` are reserved for the
+//!   variants of the abstractions, so custom variants start at `32`.
+//!
+//! # Important
+//! Codec indices of the generated variants are fixed, so enabling a grouping later does not change
+//! the encoding of proxies that already exist on chain.
+//!
+//! # Example
+//! ```ignore
+//! impl_openzeppelin_proxy_type! {
+//!     groupings: [Governance, Collator, AssetManagement, XcmTransfer],
+//!     custom: [Identity = 32 => |c| matches!(c, RuntimeCall::Identity(..))],
+//! }
+//! ```
+
+#[macro_export]
+macro_rules! impl_openzeppelin_proxy_type {
+    (
+        groupings: [$($grouping:ident),* $(,)?]
+        $(, custom: [$($custom:ident = $index:literal => |$c:ident| $filter:expr),* $(,)?])?
+        $(,)?
+    ) => {
+        $crate::impl_openzeppelin_proxy_type!(
+            @variants [] [$($grouping),*] [$($grouping),*] [$($($custom = $index => |$c| $filter),*)?]
+        );
+    };
+
+    // Assign the fixed codec index to every requested grouping variant.
+    (@variants [$($variants:tt)*] [Governance $(, $rest:ident)*] $groupings:tt $custom:tt) => {
+        $crate::impl_openzeppelin_proxy_type!(
+            @variants [$($variants)* #[codec(index = 3)] Governance,] [$($rest),*] $groupings $custom
+        );
+    };
+    (@variants [$($variants:tt)*] [Collator $(, $rest:ident)*] $groupings:tt $custom:tt) => {
+        $crate::impl_openzeppelin_proxy_type!(
+            @variants [$($variants)* #[codec(index = 4)] Collator,] [$($rest),*] $groupings $custom
+        );
+    };
+    (@variants [$($variants:tt)*] [AssetManagement $(, $rest:ident)*] $groupings:tt $custom:tt) => {
+        $crate::impl_openzeppelin_proxy_type!(
+            @variants [$($variants)* #[codec(index = 5)] AssetManagement,] [$($rest),*] $groupings $custom
+        );
+    };
+    (@variants [$($variants:tt)*] [XcmTransfer $(, $rest:ident)*] $groupings:tt $custom:tt) => {
+        $crate::impl_openzeppelin_proxy_type!(
+            @variants [$($variants)* #[codec(index = 6)] XcmTransfer,] [$($rest),*] $groupings $custom
+        );
+    };
+    (@variants [$($variants:tt)*] [Vesting $(, $rest:ident)*] $groupings:tt $custom:tt) => {
+        $crate::impl_openzeppelin_proxy_type!(
+            @variants [$($variants)* #[codec(index = 7)] Vesting,] [$($rest),*] $groupings $custom
+        );
+    };
+    (@variants $variants:tt [$unknown:ident $(, $rest:ident)*] $groupings:tt $custom:tt) => {
+        compile_error!(concat!(
+            "Unknown proxy grouping `",
+            stringify!($unknown),
+            "`. Available groupings are Governance, Collator, AssetManagement, XcmTransfer, Vesting."
+        ));
+    };
+    (
+        @variants [$($variants:tt)*] []
+        [$($grouping:ident),*]
+        [$($custom:ident = $index:literal => |$c:ident| $filter:expr),*]
+    ) => {
+        // The type of proxy that can be used to dispatch calls on behalf of an account.
+        #[derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Default,
+            parity_scale_codec::Encode,
+            parity_scale_codec::Decode,
+            parity_scale_codec::MaxEncodedLen,
+            scale_info::TypeInfo,
+            sp_runtime::RuntimeDebug,
+        )]
+        pub enum ProxyType {
+            // Fully permissioned proxy. Can execute any call on behalf of the proxied account.
+            #[default]
+            #[codec(index = 0)]
+            Any,
+            // Can execute any call that does not transfer funds or assets.
+            #[codec(index = 1)]
+            NonTransfer,
+            // Proxy with the ability to reject time-delay proxy announcements.
+            #[codec(index = 2)]
+            CancelProxy,
+            $($variants)*
+            $(
+                #[codec(index = $index)]
+                $custom,
+            )*
+        }
+
+        $(
+            const _: () = assert!(
+                $index >= 32,
+                "codec indices 0..=31 of `ProxyType` are reserved, custom variants start at 32"
+            );
+        )*
+
+        impl frame_support::traits::InstanceFilter<RuntimeCall> for ProxyType {
+            fn filter(&self, c: &RuntimeCall) -> bool {
+                match self {
+                    ProxyType::Any => true,
+                    ProxyType::NonTransfer => {
+                        matches!(
+                            c,
+                            RuntimeCall::System(..)
+                                | RuntimeCall::Scheduler(..)
+                                | RuntimeCall::Preimage(..)
+                                | RuntimeCall::Proxy(..)
+                                | RuntimeCall::Utility(..)
+                                | RuntimeCall::Multisig(..)
+                        ) $(|| $crate::impl_openzeppelin_proxy_type!(@non_transfer $grouping, c))*
+                    }
+                    ProxyType::CancelProxy => matches!(
+                        c,
+                        RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. })
+                            | RuntimeCall::Utility(..)
+                            | RuntimeCall::Multisig(..)
+                    ),
+                    $(
+                        ProxyType::$grouping => $crate::impl_openzeppelin_proxy_type!(@filter $grouping, c),
+                    )*
+                    $(
+                        ProxyType::$custom => {
+                            let $c = c;
+                            $filter
+                        }
+                    )*
+                }
+            }
+
+            fn is_superset(&self, o: &Self) -> bool {
+                match (self, o) {
+                    (x, y) if x == y => true,
+                    (ProxyType::Any, _) => true,
+                    (_, ProxyType::Any) => false,
+                    (ProxyType::NonTransfer, ProxyType::CancelProxy) => true,
+                    $(
+                        (ProxyType::NonTransfer, ProxyType::$grouping) =>
+                            $crate::impl_openzeppelin_proxy_type!(@non_transfer_superset $grouping),
+                    )*
+                    _ => false,
+                }
+            }
+        }
+    };
+
+    // Calls allowed for every grouping variant.
+    (@filter Governance, $c:ident) => {
+        matches!(
+            $c,
+            RuntimeCall::Referenda(..)
+                | RuntimeCall::ConvictionVoting(..)
+                | RuntimeCall::Whitelist(..)
+                | RuntimeCall::Treasury(..)
+                | RuntimeCall::Utility(..)
+        )
+    };
+    (@filter Collator, $c:ident) => {
+        matches!(
+            $c,
+            RuntimeCall::CollatorSelection(..) | RuntimeCall::Session(..) | RuntimeCall::Utility(..)
+        )
+    };
+    (@filter AssetManagement, $c:ident) => {
+        matches!(
+            $c,
+            RuntimeCall::Assets(
+                pallet_assets::Call::create { .. }
+                    | pallet_assets::Call::start_destroy { .. }
+                    | pallet_assets::Call::destroy_accounts { .. }
+                    | pallet_assets::Call::destroy_approvals { .. }
+                    | pallet_assets::Call::finish_destroy { .. }
+                    | pallet_assets::Call::transfer_ownership { .. }
+                    | pallet_assets::Call::set_team { .. }
+                    | pallet_assets::Call::set_metadata { .. }
+                    | pallet_assets::Call::clear_metadata { .. }
+                    | pallet_assets::Call::set_min_balance { .. }
+                    | pallet_assets::Call::mint { .. }
+                    | pallet_assets::Call::burn { .. }
+                    | pallet_assets::Call::freeze { .. }
+                    | pallet_assets::Call::block { .. }
+                    | pallet_assets::Call::thaw { .. }
+                    | pallet_assets::Call::freeze_asset { .. }
+                    | pallet_assets::Call::thaw_asset { .. }
+                    | pallet_assets::Call::touch_other { .. }
+                    | pallet_assets::Call::refund_other { .. }
+            ) | RuntimeCall::Utility(..)
+        )
+    };
+    (@filter XcmTransfer, $c:ident) => {
+        matches!(
+            $c,
+            RuntimeCall::PolkadotXcm(
+                pallet_xcm::Call::reserve_transfer_assets { .. }
+                    | pallet_xcm::Call::limited_reserve_transfer_assets { .. }
+                    | pallet_xcm::Call::transfer_assets { .. }
+                    | pallet_xcm::Call::transfer_assets_using_type_and_then { .. }
+            ) | RuntimeCall::XTokens(..)
+                | RuntimeCall::Utility(..)
+        )
+    };
+    (@filter Vesting, $c:ident) => {
+        matches!(
+            $c,
+            RuntimeCall::Vesting(
+                pallet_vesting::Call::vest { .. }
+                    | pallet_vesting::Call::vest_other { .. }
+                    | pallet_vesting::Call::merge_schedules { .. }
+            ) | RuntimeCall::Utility(..)
+        )
+    };
+
+    // Grouping calls that are also allowed for the `NonTransfer` variant.
+    (@non_transfer Governance, $c:ident) => {
+        $crate::impl_openzeppelin_proxy_type!(@filter Governance, $c)
+    };
+    (@non_transfer Collator, $c:ident) => {
+        $crate::impl_openzeppelin_proxy_type!(@filter Collator, $c)
+    };
+    (@non_transfer Vesting, $c:ident) => {
+        $crate::impl_openzeppelin_proxy_type!(@filter Vesting, $c)
+    };
+    (@non_transfer $grouping:ident, $c:ident) => {
+        false
+    };
+
+    (@non_transfer_superset Governance) => {
+        true
+    };
+    (@non_transfer_superset Collator) => {
+        true
+    };
+    (@non_transfer_superset Vesting) => {
+        true
+    };
+    (@non_transfer_superset $grouping:ident) => {
+        false
+    };
+}
+
+#[cfg(test)]
+#[allow(dead_code, non_camel_case_types)]
+mod tests {
+    use frame_support::traits::InstanceFilter;
+    use parity_scale_codec::Encode;
+
+    mod pallet_proxy {
+        pub enum Call {
+            add_proxy {},
+            reject_announcement {},
+        }
+    }
+
+    mod pallet_assets {
+        pub enum Call {
+            create {},
+            start_destroy {},
+            destroy_accounts {},
+            destroy_approvals {},
+            finish_destroy {},
+            transfer_ownership {},
+            set_team {},
+            set_metadata {},
+            clear_metadata {},
+            set_min_balance {},
+            mint {},
+            burn {},
+            freeze {},
+            block {},
+            thaw {},
+            freeze_asset {},
+            thaw_asset {},
+            touch_other {},
+            refund_other {},
+            transfer {},
+        }
+    }
+
+    mod pallet_xcm {
+        pub enum Call {
+            reserve_transfer_assets {},
+            limited_reserve_transfer_assets {},
+            transfer_assets {},
+            transfer_assets_using_type_and_then {},
+            send {},
+        }
+    }
+
+    mod pallet_vesting {
+        pub enum Call {
+            vest {},
+            vest_other {},
+            merge_schedules {},
+            vested_transfer {},
+        }
+    }
+
+    pub enum RuntimeCall {
+        System(()),
+        Scheduler(()),
+        Preimage(()),
+        Proxy(pallet_proxy::Call),
+        Balances(()),
+        Utility(()),
+        Multisig(()),
+        Referenda(()),
+        ConvictionVoting(()),
+        Whitelist(()),
+        Treasury(()),
+        CollatorSelection(()),
+        Session(()),
+        Assets(pallet_assets::Call),
+        PolkadotXcm(pallet_xcm::Call),
+        XTokens(()),
+        Vesting(pallet_vesting::Call),
+        Identity(()),
+    }
+
+    crate::impl_openzeppelin_proxy_type! {
+        groupings: [Governance, Collator, AssetManagement, XcmTransfer, Vesting],
+        custom: [Identity = 32 => |c| matches!(c, RuntimeCall::Identity(..))],
+    }
+
+    const PROXY_TYPES: [ProxyType; 9] = [
+        ProxyType::Any,
+        ProxyType::NonTransfer,
+        ProxyType::CancelProxy,
+        ProxyType::Governance,
+        ProxyType::Collator,
+        ProxyType::AssetManagement,
+        ProxyType::XcmTransfer,
+        ProxyType::Vesting,
+        ProxyType::Identity,
+    ];
+
+    fn calls() -> Vec<RuntimeCall> {
+        use pallet_assets::Call as Assets;
+        vec![
+            RuntimeCall::System(()),
+            RuntimeCall::Scheduler(()),
+            RuntimeCall::Preimage(()),
+            RuntimeCall::Proxy(pallet_proxy::Call::add_proxy {}),
+            RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement {}),
+            RuntimeCall::Balances(()),
+            RuntimeCall::Utility(()),
+            RuntimeCall::Multisig(()),
+            RuntimeCall::Referenda(()),
+            RuntimeCall::ConvictionVoting(()),
+            RuntimeCall::Whitelist(()),
+            RuntimeCall::Treasury(()),
+            RuntimeCall::CollatorSelection(()),
+            RuntimeCall::Session(()),
+            RuntimeCall::Assets(Assets::create {}),
+            RuntimeCall::Assets(Assets::start_destroy {}),
+            RuntimeCall::Assets(Assets::destroy_accounts {}),
+            RuntimeCall::Assets(Assets::destroy_approvals {}),
+            RuntimeCall::Assets(Assets::finish_destroy {}),
+            RuntimeCall::Assets(Assets::transfer_ownership {}),
+            RuntimeCall::Assets(Assets::set_team {}),
+            RuntimeCall::Assets(Assets::set_metadata {}),
+            RuntimeCall::Assets(Assets::clear_metadata {}),
+            RuntimeCall::Assets(Assets::set_min_balance {}),
+            RuntimeCall::Assets(Assets::mint {}),
+            RuntimeCall::Assets(Assets::burn {}),
+            RuntimeCall::Assets(Assets::freeze {}),
+            RuntimeCall::Assets(Assets::block {}),
+            RuntimeCall::Assets(Assets::thaw {}),
+            RuntimeCall::Assets(Assets::freeze_asset {}),
+            RuntimeCall::Assets(Assets::thaw_asset {}),
+            RuntimeCall::Assets(Assets::touch_other {}),
+            RuntimeCall::Assets(Assets::refund_other {}),
+            RuntimeCall::Assets(Assets::transfer {}),
+            RuntimeCall::PolkadotXcm(pallet_xcm::Call::reserve_transfer_assets {}),
+            RuntimeCall::PolkadotXcm(pallet_xcm::Call::limited_reserve_transfer_assets {}),
+            RuntimeCall::PolkadotXcm(pallet_xcm::Call::transfer_assets {}),
+            RuntimeCall::PolkadotXcm(pallet_xcm::Call::transfer_assets_using_type_and_then {}),
+            RuntimeCall::PolkadotXcm(pallet_xcm::Call::send {}),
+            RuntimeCall::XTokens(()),
+            RuntimeCall::Vesting(pallet_vesting::Call::vest {}),
+            RuntimeCall::Vesting(pallet_vesting::Call::vest_other {}),
+            RuntimeCall::Vesting(pallet_vesting::Call::merge_schedules {}),
+            RuntimeCall::Vesting(pallet_vesting::Call::vested_transfer {}),
+            RuntimeCall::Identity(()),
+        ]
+    }
+
+    #[test]
+    fn codec_indices_are_fixed() {
+        let indices: Vec<u8> = PROXY_TYPES.iter().flat_map(Encode::encode).collect();
+        assert_eq!(indices, [0, 1, 2, 3, 4, 5, 6, 7, 32]);
+    }
+
+    #[test]
+    fn any_allows_every_call() {
+        assert!(calls().iter().all(|c| ProxyType::Any.filter(c)));
+    }
+
+    #[test]
+    fn non_transfer_rejects_transfers() {
+        let transfers = [
+            RuntimeCall::Balances(()),
+            RuntimeCall::Assets(pallet_assets::Call::transfer {}),
+            RuntimeCall::Assets(pallet_assets::Call::mint {}),
+            RuntimeCall::PolkadotXcm(pallet_xcm::Call::transfer_assets {}),
+            RuntimeCall::XTokens(()),
+            RuntimeCall::Vesting(pallet_vesting::Call::vested_transfer {}),
+        ];
+        assert!(!transfers.iter().any(|c| ProxyType::NonTransfer.filter(c)));
+
+        assert!(ProxyType::NonTransfer.filter(&RuntimeCall::System(())));
+        assert!(ProxyType::NonTransfer.filter(&RuntimeCall::Referenda(())));
+        assert!(ProxyType::NonTransfer.filter(&RuntimeCall::Session(())));
+        assert!(ProxyType::NonTransfer.filter(&RuntimeCall::Vesting(pallet_vesting::Call::vest {})));
+    }
+
+    #[test]
+    fn grouping_filters() {
+        let cancel = RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement {});
+        assert!(ProxyType::CancelProxy.filter(&cancel));
+        assert!(
+            !ProxyType::CancelProxy.filter(&RuntimeCall::Proxy(pallet_proxy::Call::add_proxy {}))
+        );
+
+        assert!(
+            ProxyType::AssetManagement.filter(&RuntimeCall::Assets(pallet_assets::Call::mint {}))
+        );
+        assert!(!ProxyType::AssetManagement
+            .filter(&RuntimeCall::Assets(pallet_assets::Call::transfer {})));
+
+        assert!(ProxyType::XcmTransfer.filter(&RuntimeCall::PolkadotXcm(
+            pallet_xcm::Call::transfer_assets {}
+        )));
+        assert!(
+            !ProxyType::XcmTransfer.filter(&RuntimeCall::PolkadotXcm(pallet_xcm::Call::send {}))
+        );
+
+        assert!(
+            ProxyType::Vesting.filter(&RuntimeCall::Vesting(pallet_vesting::Call::vest_other {}))
+        );
+        assert!(!ProxyType::Vesting.filter(&RuntimeCall::Vesting(
+            pallet_vesting::Call::vested_transfer {}
+        )));
+
+        assert!(ProxyType::Identity.filter(&RuntimeCall::Identity(())));
+        assert!(!ProxyType::Identity.filter(&RuntimeCall::System(())));
+    }
+
+    #[test]
+    fn supersets_allow_every_call_of_their_subsets() {
+        let calls = calls();
+        for superset in PROXY_TYPES {
+            for subset in PROXY_TYPES {
+                if !superset.is_superset(&subset) {
+                    continue;
+                }
+                for c in calls.iter().filter(|c| subset.filter(c)) {
+                    assert!(
+                        superset.filter(c),
+                        "{superset:?} is a superset of {subset:?} but rejects one of its calls"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn non_transfer_is_superset_of_non_transfer_groupings() {
+        assert!(ProxyType::NonTransfer.is_superset(&ProxyType::CancelProxy));
+        assert!(ProxyType::NonTransfer.is_superset(&ProxyType::Governance));
+        assert!(ProxyType::NonTransfer.is_superset(&ProxyType::Collator));
+        assert!(ProxyType::NonTransfer.is_superset(&ProxyType::Vesting));
+        assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::AssetManagement));
+        assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::XcmTransfer));
+        assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::Any));
+    }
+}
@@ -0,0 +1,85 @@
+//! Helpers for reading verified relay chain state.
+//!
+//! The values are read from the validation data and the relay chain state proof that
+//! `cumulus_pallet_parachain_system` verified while applying the `set_validation_data` inherent.
+//! The pallet kills `ValidationData` in its `on_initialize`, so until the inherent is applied in the
+//! current block (e.g. in the `on_initialize` of other pallets) all helpers reading the relay parent
+//! return `None`. Only [`hrmp_channel_capacity`] still returns a value there, which is the capacity
+//! seen by the relay parent of the previous block.
+//!
+//! The helpers only read state and can be used from any grouping, e.g. in `OnSystemEvent`
+//! handlers, for XCM fee tuning or as a source of randomness.
+
+use cumulus_pallet_parachain_system::{
+    Config, RelayChainStateProof, RelayStateProof, RelevantMessagingState, ValidationData,
+};
+use cumulus_primitives_core::{
+    relay_chain::{well_known_keys, BlockNumber as RelayBlockNumber, HeadData},
+    ParaId,
+};
+use frame_support::traits::Get;
+
+/// Remaining capacity of an outbound HRMP channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HrmpChannelCapacity {
+    /// Number of messages that can still be sent.
+    pub messages: u32,
+    /// Number of bytes that can still be sent.
+    pub bytes: u32,
+    /// Maximum size of a single message.
+    pub max_message_size: u32,
+}
+
+/// Returns the number of the relay parent block.
+pub fn relay_block_number<T: Config>() -> Option<RelayBlockNumber> {
+    ValidationData::<T>::get().map(|data| data.relay_parent_number)
+}
+
+/// Returns the relay chain state proof checked against the storage root of the relay parent.
+pub fn relay_state_proof<T: Config>() -> Option<RelayChainStateProof> {
+    let data = ValidationData::<T>::get()?;
+    let proof = RelayStateProof::<T>::get()?;
+    RelayChainStateProof::new(T::SelfParaId::get(), data.relay_parent_storage_root, proof).ok()
+}
+
+/// Returns the BABE randomness of the current relay chain epoch.
+///
+/// It is known one epoch in advance, so it must not be used where the outcome should not be predictable.
+pub fn relay_epoch_randomness<T: Config>() -> Option<[u8; 32]> {
+    relay_state_proof::<T>()?
+        .read_optional_entry(well_known_keys::ONE_EPOCH_AGO_RANDOMNESS)
+        .ok()
+        .flatten()
+}
+
+/// Returns the BABE VRF output of the relay parent block author.
+pub fn relay_block_randomness<T: Config>() -> Option<[u8; 32]> {
+    relay_state_proof::<T>()?
+        .read_optional_entry::<Option<[u8; 32]>>(well_known_keys::CURRENT_BLOCK_RANDOMNESS)
+        .ok()
+        .flatten()
+        .flatten()
+}
+
+/// Returns the remaining capacity of the outbound HRMP channel to `recipient`, if the channel is open.
+pub fn hrmp_channel_capacity<T: Config>(recipient: ParaId) -> Option<HrmpChannelCapacity> {
+    let state = RelevantMessagingState::<T>::get()?;
+    let index = state
+        .egress_channels
+        .binary_search_by_key(&recipient, |(id, _)| *id)
+        .ok()?;
+    let (_, channel) = &state.egress_channels[index];
+    Some(HrmpChannelCapacity {
+        messages: channel.max_capacity.saturating_sub(channel.msg_count),
+        bytes: channel.max_total_size.saturating_sub(channel.total_size),
+        max_message_size: channel.max_message_size,
+    })
+}
+
+/// Returns the head data of `para_id` as seen by the relay parent.
+pub fn sibling_head_data<T: Config>(para_id: ParaId) -> Option<HeadData> {
+    relay_state_proof::<T>()?
+        .read_optional_entry(&well_known_keys::para_head(para_id))
+        .ok()
+        .flatten()
+}
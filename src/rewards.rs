@@ -0,0 +1,443 @@
+//! Issuance and distribution of collator rewards.
+//!
+//! The Consensus abstraction mints new issuance every block ([`BlockRewards`], wired into
+//! `pallet_authorship::Config::EventHandler`) and at the end of every session ([`SessionRewards`], wrapping
+//! the `pallet_session::Config::SessionManager` of the staking backend). The curves are selected with
+//! `ConsensusConfig::BlockIssuance` and `ConsensusConfig::SessionIssuance`, and no issuance happens by default.
+//!
+//! Each reward is split between the Treasury, the staking pot and the collator pot (`ConsensusConfig::PotId`)
+//! and is capped so that the total issuance never exceeds `ConsensusConfig::MaxSupply`. Without a Treasury
+//! account (`ConsensusConfig::RewardTreasury` is `None`) its share is paid to the staking pot. Every minted
+//! share emits a `pallet_balances::Event::Minted` event for its destination account, and a share that can not
+//! be minted is logged.
+//!
+//! Every distribution is reported to `ConsensusConfig::OnRewardsDistributed`. The `Rewards` pallet of this
+//! module, configured by [`impl_openzeppelin_rewards!`](crate::impl_openzeppelin_rewards), deposits an
+//! [`Event::RewardsDistributed`] for it.
+//!
+//! With the `CollatorSelection` backend there are no stakers, so the staking pot is the collator pot
+//! (`ConsensusConfig::PotId`) and the staking share is paid to the collators as well. It is still reported
+//! separately in [`RewardsDistributed`].
+//!
+//! Rewards are minted in `on_initialize` and `on_finalize` hooks, so their weight is registered as
+//! mandatory extra weight of the block.
+
+use core::marker::PhantomData;
+
+use frame_support::{
+    dispatch::DispatchClass,
+    traits::{
+        fungible::{Inspect, Mutate},
+        Get,
+    },
+};
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    Perbill,
+};
+use sp_std::prelude::Vec;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use frame_support::pallet_prelude::*;
+
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Balance of the native currency.
+        type Balance: Parameter + MaxEncodedLen;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// New issuance was minted as rewards. Amounts are the shares that were actually minted.
+        RewardsDistributed {
+            collators: T::Balance,
+            staking: T::Balance,
+            treasury: T::Balance,
+        },
+    }
+}
+
+impl<T: Config> OnRewardsDistributed<T::Balance> for Pallet<T> {
+    fn on_rewards_distributed(rewards: &RewardsDistributed<T::Balance>) {
+        Self::deposit_event(Event::RewardsDistributed {
+            collators: rewards.collators.clone(),
+            staking: rewards.staking.clone(),
+            treasury: rewards.treasury.clone(),
+        });
+        frame_system::Pallet::<T>::register_extra_weight_unchecked(
+            T::DbWeight::get().writes(1),
+            DispatchClass::Mandatory,
+        );
+    }
+}
+
+pub type BalanceOf<R> =
+    <<R as RewardsConfig>::Currency as Inspect<<R as RewardsConfig>::AccountId>>::Balance;
+
+/// Destinations and limits of the rewards. Implemented by the `ConsensusRewards` type generated by
+/// `impl_openzeppelin_consensus!`.
+pub trait RewardsConfig {
+    /// The runtime the weight of the rewards is registered in.
+    type Runtime: frame_system::Config;
+    type AccountId: core::fmt::Debug;
+    /// The currency the rewards are minted in.
+    type Currency: Mutate<Self::AccountId>;
+    /// Upper bound of the total issuance. `None` means that the supply is not capped.
+    type MaxSupply: Get<Option<BalanceOf<Self>>>;
+    /// Share of every reward sent to the Treasury.
+    type TreasuryShare: Get<Perbill>;
+    /// Share of every reward sent to the staking pot.
+    type StakingShare: Get<Perbill>;
+    /// Treasury account. When it is `None`, the Treasury share is paid to the staking pot.
+    type Treasury: Get<Option<Self::AccountId>>;
+    /// Pot that collators are paid from.
+    type CollatorPot: Get<Self::AccountId>;
+    /// Pot that stakers are paid from. It is the collator pot with the `CollatorSelection` backend.
+    type StakingPot: Get<Self::AccountId>;
+    /// Handler of every distribution, e.g. the `Rewards` pallet depositing an event.
+    type OnRewardsDistributed: OnRewardsDistributed<BalanceOf<Self>>;
+}
+
+/// Shares of a reward that were minted.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RewardsDistributed<Balance> {
+    /// Minted into the collator pot.
+    pub collators: Balance,
+    /// Minted into the staking pot, including the Treasury share when there is no Treasury account.
+    pub staking: Balance,
+    /// Minted into the Treasury account.
+    pub treasury: Balance,
+}
+
+/// Handler of distributed rewards.
+pub trait OnRewardsDistributed<Balance> {
+    fn on_rewards_distributed(rewards: &RewardsDistributed<Balance>);
+}
+
+impl<Balance> OnRewardsDistributed<Balance> for () {
+    fn on_rewards_distributed(_rewards: &RewardsDistributed<Balance>) {}
+}
+
+/// Amount of new issuance for a reward period (a block or a session).
+pub trait IssuanceCurve<Balance> {
+    fn issuance(total_issuance: Balance) -> Balance;
+}
+
+/// No new issuance. Collators are only paid from what is sent to the pot.
+impl<Balance: Zero> IssuanceCurve<Balance> for () {
+    fn issuance(_total_issuance: Balance) -> Balance {
+        Zero::zero()
+    }
+}
+
+/// Issues a fixed amount every period.
+pub struct FixedIssuance<Amount>(PhantomData<Amount>);
+impl<Balance, Amount: Get<Balance>> IssuanceCurve<Balance> for FixedIssuance<Amount> {
+    fn issuance(_total_issuance: Balance) -> Balance {
+        Amount::get()
+    }
+}
+
+/// Inflates the total issuance by `Rate` per year, spread over `PeriodsPerYear` periods.
+pub struct YearlyInflation<Rate, PeriodsPerYear>(PhantomData<(Rate, PeriodsPerYear)>);
+impl<Balance, Rate, PeriodsPerYear> IssuanceCurve<Balance> for YearlyInflation<Rate, PeriodsPerYear>
+where
+    Balance: sp_runtime::traits::AtLeast32BitUnsigned + Copy,
+    Rate: Get<Perbill>,
+    PeriodsPerYear: Get<u32>,
+{
+    fn issuance(total_issuance: Balance) -> Balance {
+        Rate::get().mul_floor(total_issuance) / Balance::from(PeriodsPerYear::get().max(1))
+    }
+}
+
+/// Mints `amount`, capped by `RewardsConfig::MaxSupply`, splits it between the Treasury, the staking pot
+/// and the collator pot and reports the minted shares to `RewardsConfig::OnRewardsDistributed`.
+pub fn distribute<R: RewardsConfig>(amount: BalanceOf<R>) {
+    // The total issuance is read by the issuance curves and the supply cap.
+    let mut reads = 1;
+    let mut writes = 0;
+
+    let amount = match R::MaxSupply::get() {
+        Some(max_supply) => amount.min(max_supply.saturating_sub(R::Currency::total_issuance())),
+        None => amount,
+    };
+    let treasury = R::TreasuryShare::get().mul_floor(amount);
+    let staking = R::StakingShare::get()
+        .mul_floor(amount)
+        .min(amount.saturating_sub(treasury));
+    let collators = amount.saturating_sub(treasury).saturating_sub(staking);
+    let (treasury, staking) = match R::Treasury::get() {
+        Some(account) => (Some((account, treasury)), staking),
+        None => (None, staking.saturating_add(treasury)),
+    };
+
+    let mut pay = |who: R::AccountId, share: BalanceOf<R>| {
+        if share.is_zero() {
+            return share;
+        }
+        // Minting reads and writes the account and writes the total issuance.
+        reads += 1;
+        writes += 2;
+        mint::<R>(&who, share)
+    };
+    let rewards = RewardsDistributed {
+        treasury: treasury.map_or_else(Zero::zero, |(who, share)| pay(who, share)),
+        staking: pay(R::StakingPot::get(), staking),
+        collators: pay(R::CollatorPot::get(), collators),
+    };
+    if !(rewards.collators.is_zero() && rewards.staking.is_zero() && rewards.treasury.is_zero()) {
+        R::OnRewardsDistributed::on_rewards_distributed(&rewards);
+    }
+
+    frame_system::Pallet::<R::Runtime>::register_extra_weight_unchecked(
+        <R::Runtime as frame_system::Config>::DbWeight::get().reads_writes(reads, writes),
+        DispatchClass::Mandatory,
+    );
+}
+
+/// Returns the minted amount.
+fn mint<R: RewardsConfig>(who: &R::AccountId, amount: BalanceOf<R>) -> BalanceOf<R> {
+    // Minting only fails if the amount would not reach the existential deposit of an empty account
+    // or would overflow the total issuance, in which case the share is dropped.
+    R::Currency::mint_into(who, amount).unwrap_or_else(|error| {
+        log::error!(
+            target: "runtime::rewards",
+            "Failed to mint reward of {:?} into {:?}: {:?}",
+            amount,
+            who,
+            error,
+        );
+        Zero::zero()
+    })
+}
+
+/// Distributes the block issuance of `Curve` when the block author is noted.
+pub struct BlockRewards<R, Curve>(PhantomData<(R, Curve)>);
+impl<R, Curve, Author, BlockNumber> pallet_authorship::EventHandler<Author, BlockNumber>
+    for BlockRewards<R, Curve>
+where
+    R: RewardsConfig,
+    Curve: IssuanceCurve<BalanceOf<R>>,
+{
+    fn note_author(_author: Author) {
+        distribute::<R>(Curve::issuance(R::Currency::total_issuance()));
+    }
+}
+
+/// Distributes the session issuance of `Curve` at the end of every session, before handing over to the
+/// `Inner` session manager, so that the staking backend can pay out the rewards of the ending session.
+pub struct SessionRewards<R, Curve, Inner>(PhantomData<(R, Curve, Inner)>);
+impl<R, Curve, Inner, ValidatorId> pallet_session::SessionManager<ValidatorId>
+    for SessionRewards<R, Curve, Inner>
+where
+    R: RewardsConfig,
+    Curve: IssuanceCurve<BalanceOf<R>>,
+    Inner: pallet_session::SessionManager<ValidatorId>,
+{
+    fn new_session(new_index: u32) -> Option<Vec<ValidatorId>> {
+        Inner::new_session(new_index)
+    }
+
+    fn new_session_genesis(new_index: u32) -> Option<Vec<ValidatorId>> {
+        Inner::new_session_genesis(new_index)
+    }
+
+    fn start_session(start_index: u32) {
+        Inner::start_session(start_index)
+    }
+
+    fn end_session(end_index: u32) {
+        distribute::<R>(Curve::issuance(R::Currency::total_issuance()));
+        Inner::end_session(end_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use frame_support::{derive_impl, parameter_types, weights::constants::RocksDbWeight};
+    use sp_runtime::BuildStorage;
+
+    use super::*;
+
+    type Block = frame_system::mocking::MockBlock<Test>;
+
+    frame_support::construct_runtime!(
+        pub enum Test {
+            System: frame_system,
+            Balances: pallet_balances,
+            Rewards: crate::rewards,
+        }
+    );
+
+    #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+    impl frame_system::Config for Test {
+        type AccountData = pallet_balances::AccountData<u64>;
+        type Block = Block;
+        type DbWeight = RocksDbWeight;
+    }
+
+    #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+    impl pallet_balances::Config for Test {
+        type AccountStore = System;
+    }
+
+    impl Config for Test {
+        type Balance = u64;
+        type RuntimeEvent = RuntimeEvent;
+    }
+
+    const TREASURY: u64 = 1;
+    const STAKING_POT: u64 = 2;
+    const COLLATOR_POT: u64 = 3;
+
+    parameter_types! {
+        pub static MaxSupply: Option<u64> = None;
+        pub static TreasuryShare: Perbill = Perbill::from_percent(20);
+        pub static StakingShare: Perbill = Perbill::from_percent(30);
+        pub static Treasury: Option<u64> = Some(TREASURY);
+        pub const StakingPot: u64 = STAKING_POT;
+        pub const CollatorPot: u64 = COLLATOR_POT;
+        pub const Rate: Perbill = Perbill::from_percent(10);
+        pub const PeriodsPerYear: u32 = 4;
+        pub const NoPeriods: u32 = 0;
+        pub const Amount: u64 = 500;
+    }
+
+    struct ConsensusRewards;
+    impl RewardsConfig for ConsensusRewards {
+        type AccountId = u64;
+        type CollatorPot = CollatorPot;
+        type Currency = Balances;
+        type MaxSupply = MaxSupply;
+        type OnRewardsDistributed = Rewards;
+        type Runtime = Test;
+        type StakingPot = StakingPot;
+        type StakingShare = StakingShare;
+        type Treasury = Treasury;
+        type TreasuryShare = TreasuryShare;
+    }
+
+    fn new_test_ext() -> sp_io::TestExternalities {
+        let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap()
+            .into();
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+
+    fn balances() -> [u64; 3] {
+        [TREASURY, STAKING_POT, COLLATOR_POT].map(Balances::free_balance)
+    }
+
+    #[test]
+    fn distribute_splits_the_reward() {
+        new_test_ext().execute_with(|| {
+            distribute::<ConsensusRewards>(1_000);
+            assert_eq!(balances(), [200, 300, 500]);
+            assert_eq!(Balances::total_issuance(), 1_000);
+        });
+    }
+
+    #[test]
+    fn distribute_registers_its_weight() {
+        new_test_ext().execute_with(|| {
+            distribute::<ConsensusRewards>(1_000);
+            assert_eq!(
+                *frame_system::BlockWeight::<Test>::get().get(DispatchClass::Mandatory),
+                RocksDbWeight::get().reads_writes(4, 7)
+            );
+        });
+    }
+
+    #[test]
+    fn distribute_deposits_an_event() {
+        new_test_ext().execute_with(|| {
+            distribute::<ConsensusRewards>(1_000);
+            System::assert_last_event(
+                Event::RewardsDistributed {
+                    collators: 500,
+                    staking: 300,
+                    treasury: 200,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn distribute_pays_the_treasury_share_to_stakers_without_treasury() {
+        new_test_ext().execute_with(|| {
+            Treasury::set(None);
+            distribute::<ConsensusRewards>(1_000);
+            assert_eq!(balances(), [0, 500, 500]);
+            System::assert_last_event(
+                Event::RewardsDistributed {
+                    collators: 500,
+                    staking: 500,
+                    treasury: 0,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn distribute_is_capped_by_max_supply() {
+        new_test_ext().execute_with(|| {
+            MaxSupply::set(Some(1_500));
+            distribute::<ConsensusRewards>(1_000);
+            distribute::<ConsensusRewards>(1_000);
+            distribute::<ConsensusRewards>(1_000);
+            assert_eq!(Balances::total_issuance(), 1_500);
+        });
+    }
+
+    #[test]
+    fn distribute_never_mints_more_than_the_reward() {
+        new_test_ext().execute_with(|| {
+            StakingShare::set(Perbill::from_percent(90));
+            distribute::<ConsensusRewards>(1_000);
+            assert_eq!(balances(), [200, 800, 0]);
+            assert_eq!(Balances::total_issuance(), 1_000);
+        });
+    }
+
+    #[test]
+    fn distribute_skips_empty_shares() {
+        new_test_ext().execute_with(|| {
+            TreasuryShare::set(Perbill::zero());
+            distribute::<ConsensusRewards>(1_000);
+            assert_eq!(balances(), [0, 300, 700]);
+            assert_eq!(
+                *frame_system::BlockWeight::<Test>::get().get(DispatchClass::Mandatory),
+                RocksDbWeight::get().reads_writes(3, 5)
+            );
+        });
+    }
+
+    #[test]
+    fn issuance_curves() {
+        assert_eq!(<() as IssuanceCurve<u64>>::issuance(1_000_000), 0);
+        assert_eq!(FixedIssuance::<Amount>::issuance(1_000_000), 500);
+        assert_eq!(
+            YearlyInflation::<Rate, PeriodsPerYear>::issuance(1_000_000u64),
+            25_000
+        );
+        assert_eq!(
+            YearlyInflation::<Rate, NoPeriods>::issuance(1_000_000u64),
+            100_000
+        );
+    }
+}
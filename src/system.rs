@@ -12,6 +12,22 @@
 //! - `cumulus_pallet_parachain_system`
 //! - `pallet_multisig`
 //!
+//! The deposits of `pallet_proxy` and `pallet_multisig` are held by the `Deposits` pallet of
+//! [`crate::deposits`], listed separately in [`DEPOSITS_PALLET_NAMES`] (`Deposits` in
+//! `construct_openzeppelin_runtime`). Pure proxies are filtered unless `SystemConfig::AllowPureProxies` is set.
+//!
+//! The migrations required by the defaults of the abstractions, e.g. moving reserved deposits onto holds,
+//! run before `SystemConfig::SingleBlockMigrations`. See [`crate::migrations`].
+//!
+//! Pass the `MultiBlockMigrations` mode to also configure `pallet_migrations` as the multi-block migrator
+//! and run `SystemConfig::MultiBlockMigrations`. Its pallet is listed separately in [`MIGRATIONS_PALLET_NAMES`]
+//! (`MultiBlockMigrations` in `construct_openzeppelin_runtime`), so that adding it does not shift the
+//! indices of the pallets of existing runtimes.
+//!
+//! The currency units used by the abstractions (`CENTS`, `MICROCENTS` and the `deposit()` function) are
+//! taken from the runtime. They can be generated from `SystemConfig::TokenDecimals` with
+//! [`impl_openzeppelin_currency_units!`].
+//!
 //! # Parameters
 //! - `$t`: A type that implements the `SystemConfig` trait, providing the necessary associated types
 //!   and configurations for core system functionality.
@@ -22,21 +38,21 @@
 
 #[macro_export]
 macro_rules! impl_openzeppelin_system {
-    ($t:ty) => {
+    (@common $t:ty, $migrator:ty) => {
         pub struct NormalFilter;
         impl Contains<RuntimeCall> for NormalFilter {
             fn contains(c: &RuntimeCall) -> bool {
-                match c {
-                    // We filter anonymous proxy as they make "reserve" inconsistent
-                    // See: https://github.com/paritytech/polkadot-sdk/blob/v1.9.0-rc2/substrate/frame/proxy/src/lib.rs#L260
-                    RuntimeCall::Proxy(method) => !matches!(
-                        method,
-                        pallet_proxy::Call::create_pure { .. }
-                            | pallet_proxy::Call::kill_pure { .. }
-                            | pallet_proxy::Call::remove_proxies { .. }
-                    ),
+                let allowed = match c {
+                    // Pure proxies are only allowed with `SystemConfig::AllowPureProxies`. Their deposits are held
+                    // with a dedicated reason, so they no longer make "reserve" inconsistent.
+                    RuntimeCall::Proxy(
+                        pallet_proxy::Call::create_pure { .. } | pallet_proxy::Call::kill_pure { .. },
+                    ) => <<$t as SystemConfig>::AllowPureProxies as frame_support::traits::Get<bool>>::get(),
                     _ => true,
-                }
+                };
+                // Additional runtime filter, e.g. the maintenance mode of a Tanssi container chain.
+                allowed
+                    && <<$t as SystemConfig>::CallFilter as Contains<RuntimeCall>>::contains(c)
             }
         }
 
@@ -97,6 +113,8 @@ macro_rules! impl_openzeppelin_system {
             type MaxConsumers = <$t as SystemConfig>::MaxConsumers;
             // The index type for storing how many extrinsics an account has signed.
             type Nonce = Nonce;
+            // The migrator that is used to run Multi-Block-Migrations.
+            type MultiBlockMigrator = $migrator;
             // The action to take on a Runtime Upgrade
             type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
             // Converts a module to an index of this module in the runtime.
@@ -109,13 +127,20 @@ macro_rules! impl_openzeppelin_system {
             type RuntimeOrigin = RuntimeOrigin;
             // This is used as an identifier of the chain. 42 is the generic substrate prefix.
             type SS58Prefix = <$t as SystemConfig>::SS58Prefix;
+            // All migrations that should run in the next runtime upgrade, starting with the ones required by the
+            // defaults of the abstractions.
+            type SingleBlockMigrations = (
+                $crate::migrations::AbstractionMigrations<Runtime>,
+                <$t as SystemConfig>::SingleBlockMigrations,
+            );
             // Runtime version.
             type Version = <$t as SystemConfig>::Version;
         }
 
         // A pallet that provides a way for consensus systems to set and check the onchain time.
         impl pallet_timestamp::Config for Runtime {
-            // Timestamp must increment by at least <MinimumPeriod> between sequential blocks
+            // Timestamp must increment by at least <MinimumPeriod> between sequential blocks.
+            // With the Consensus abstraction, pass the generated `TimestampMinimumPeriod`.
             type MinimumPeriod = <$t as SystemConfig>::SlotDuration;
             // A timestamp: milliseconds since the unix epoch.
             type Moment = u64;
@@ -129,20 +154,20 @@ macro_rules! impl_openzeppelin_system {
         impl parachain_info::Config for Runtime {}
 
         parameter_types! {
-            pub MaximumSchedulerWeight: frame_support::weights::Weight = Perbill::from_percent(80) *
-                RuntimeBlockWeights::get().max_block;
-            pub const MaxScheduledRuntimeCallsPerBlock: u32 = 50;
+            pub MaximumSchedulerWeight: frame_support::weights::Weight = Perbill::from_percent(
+                <<$t as SystemConfig>::SchedulerMaximumWeightPercent as frame_support::traits::Get<u32>>::get()
+            ) * RuntimeBlockWeights::get().max_block;
         }
 
         // A Pallet for scheduling runtime calls.
         impl pallet_scheduler::Config for Runtime {
             // The maximum number of scheduled calls in the queue for a single block.
-            type MaxScheduledPerBlock = MaxScheduledRuntimeCallsPerBlock;
+            type MaxScheduledPerBlock = <$t as SystemConfig>::MaxScheduledPerBlock;
             // The maximum weight that may be scheduled per block for any dispatchables.
             type MaximumWeight = MaximumSchedulerWeight;
             // Compare the privileges of origins. This will be used when canceling a task,
             // to ensure that the origin that tries to cancel has greater or equal privileges as the origin that created the scheduled task.
-            type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
+            type OriginPrivilegeCmp = <$t as SystemConfig>::SchedulerOriginPrivilegeCmp;
             // The caller origin, overarching type of all pallets origins.
             type PalletsOrigin = OriginCaller;
             // The preimage provider with which we look up call hashes to get the call.
@@ -185,6 +210,20 @@ macro_rules! impl_openzeppelin_system {
             type WeightInfo = <$t as SystemWeight>::Preimage;
         }
 
+        parameter_types! {
+            pub const ProxyHoldReason: RuntimeHoldReason =
+                RuntimeHoldReason::Deposits($crate::deposits::HoldReason::Proxy);
+            pub const MultisigHoldReason: RuntimeHoldReason =
+                RuntimeHoldReason::Deposits($crate::deposits::HoldReason::Multisig);
+        }
+
+        // Holds the deposits of `pallet_proxy` and `pallet_multisig`, which only support reserves.
+        impl $crate::deposits::Config for Runtime {
+            type Balance = Balance;
+            type Currency = Balances;
+            type RuntimeHoldReason = RuntimeHoldReason;
+        }
+
         parameter_types! {
             pub const ProxyDepositBase: Balance = deposit(1, 40);
             pub const AnnouncementDepositBase: Balance = deposit(1, 48);
@@ -199,8 +238,8 @@ macro_rules! impl_openzeppelin_system {
             type AnnouncementDepositFactor = AnnouncementDepositFactor;
             // The type of hash used for hashing the call.
             type CallHasher = BlakeTwo256;
-            // The currency mechanism.
-            type Currency = Balances;
+            // The currency mechanism, holding the deposits with the `Proxy` reason.
+            type Currency = $crate::deposits::HoldCurrency<Balances, ProxyHoldReason>;
             // The maximum amount of time-delayed announcements that are allowed to be pending.
             type MaxPending = <$t as SystemConfig>::MaxPendingProxies;
             // The maximum amount of proxies allowed for a single account.
@@ -229,8 +268,9 @@ macro_rules! impl_openzeppelin_system {
             type DustRemoval = ();
             // The minimum amount required to keep an account open. MUST BE GREATER THAN ZERO!
             type ExistentialDeposit = <$t as SystemConfig>::ExistentialDeposit;
-            // The ID type for freezes.
-            type FreezeIdentifier = ();
+            // The ID type for freezes. Pallets freezing funds, such as the `CollatorStaking` backend, add their
+            // reasons to `RuntimeFreezeReason`.
+            type FreezeIdentifier = RuntimeFreezeReason;
             // The maximum number of individual freeze locks that can exist on an account at any time.
             type MaxFreezes = <$t as SystemConfig>::MaxFreezes;
             // The maximum number of locks that should exist on an account. Not strictly enforced but used for weight estimation.
@@ -265,11 +305,9 @@ macro_rules! impl_openzeppelin_system {
 
         // base pallet for Cumulus-based parachains.
         impl cumulus_pallet_parachain_system::Config for Runtime {
-            // Checks if the associated relay parent block number is valid. Depending on the feature, it ensures the relay number increases as expected.
-            #[cfg(not(feature = "async-backing"))]
-            type CheckAssociatedRelayNumber = RelayNumberStrictlyIncreases;
-            #[cfg(feature = "async-backing")]
-            type CheckAssociatedRelayNumber = RelayNumberMonotonicallyIncreases;
+            // Checks if the associated relay parent block number is valid. With the Consensus abstraction, pass the
+            // generated `CheckAssociatedRelayNumber` so that it follows `ConsensusConfig::ConsensusMode`.
+            type CheckAssociatedRelayNumber = <$t as SystemConfig>::CheckAssociatedRelayNumber;
             // An entry-point for managing the backlog of unincluded parachain blocks and authorship rights for those blocks.
             type ConsensusHook = <$t as SystemConfig>::ConsensusHook;
             // Queues inbound downward messages for delayed processing.
@@ -277,7 +315,7 @@ macro_rules! impl_openzeppelin_system {
             // The handler is expected to eventually process all the messages that are pushed to it.
             type DmpQueue = frame_support::traits::EnqueueWithOrigin<MessageQueue, RelayOrigin>;
             // Something which can be notified when the validation data is set.
-            type OnSystemEvent = ();
+            type OnSystemEvent = <$t as SystemConfig>::OnSystemEvent;
             // The place where outbound XCMP messages come from. This is queried in `finalize_block`.
             type OutboundXcmpMessageSource = XcmpQueue;
             // The weight reserved at the beginning of the block for processing DMP messages.
@@ -303,8 +341,8 @@ macro_rules! impl_openzeppelin_system {
 
         // A pallet for doing multisig dispatch.
         impl pallet_multisig::Config for Runtime {
-            // The currency mechanism.
-            type Currency = Balances;
+            // The currency mechanism, holding the deposits with the `Multisig` reason.
+            type Currency = $crate::deposits::HoldCurrency<Balances, MultisigHoldReason>;
             // The base amount of currency needed to reserve for creating a multisig execution or to store a dispatch call for later.
             type DepositBase = DepositBase;
             // The amount of currency needed per unit threshold when creating a multisig execution.
@@ -317,7 +355,96 @@ macro_rules! impl_openzeppelin_system {
             type RuntimeEvent = RuntimeEvent;
             type WeightInfo = <$t as SystemWeight>::Multisig;
         }
+    };
 
+    ($t:ty) => {
+        $crate::impl_openzeppelin_system!(@common $t, ());
+    };
+    ($t:ty, MultiBlockMigrations) => {
+        $crate::impl_openzeppelin_system!(@common $t, MultiBlockMigrations);
+
+        parameter_types! {
+            pub MultiBlockMigrationsServiceWeight: Weight = Perbill::from_percent(80) *
+                RuntimeBlockWeights::get().max_block;
+        }
+
+        // A pallet to execute multi-block migrations.
+        impl pallet_migrations::Config for Runtime {
+            // The maximal length of an encoded cursor.
+            type CursorMaxLen = ConstU32<65_536>;
+            // Called when a migration failed. Freezes the chain until governance intervenes.
+            type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+            // The maximal length of an encoded identifier.
+            type IdentifierMaxLen = ConstU32<256>;
+            // The maximum weight to spend each block to execute migrations.
+            type MaxServiceWeight = MultiBlockMigrationsServiceWeight;
+            // Notifications for status updates of a runtime upgrade.
+            type MigrationStatusHandler = ();
+            // All the multi-block migrations to run.
+            #[cfg(not(feature = "runtime-benchmarks"))]
+            type Migrations = <$t as SystemConfig>::MultiBlockMigrations;
+            // Benchmarks need mocked migrations to guarantee that they succeed.
+            #[cfg(feature = "runtime-benchmarks")]
+            type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+            // The overarching event type.
+            type RuntimeEvent = RuntimeEvent;
+            type WeightInfo = <$t as SystemWeight>::Migrations;
+        }
+    };
+    ($t:ty, $mode:ident) => {
+        compile_error!(concat!(
+            "Unknown system mode `",
+            stringify!($mode),
+            "`. Available modes are MultiBlockMigrations."
+        ));
+    };
+}
+
+/// Generates the currency units derived from `SystemConfig::TokenDecimals`: `UNIT`, `CENTS`, `MILLICENTS`,
+/// `MICROCENTS`, `EXISTENTIAL_DEPOSIT`, the `deposit()` function used for storage deposits and
+/// `chain_spec_properties()` for the chain specification.
+///
+/// It is opt-in, so that runtimes which already define these constants keep their own. The token must have
+/// at least 8 decimals, otherwise `MICROCENTS` would round down to zero and with it `TransactionByteFee`.
+#[macro_export]
+macro_rules! impl_openzeppelin_currency_units {
+    ($t:ty) => {
+        const _: () = assert!(
+            <<$t as SystemConfig>::TokenDecimals as $crate::ConstGet<u8>>::VALUE >= 8,
+            "`SystemConfig::TokenDecimals` must be at least 8, otherwise `MICROCENTS` rounds down to zero"
+        );
+
+        // Currency units derived from the number of decimals of the native token.
+        pub const UNIT: Balance =
+            (10 as Balance).pow(<<$t as SystemConfig>::TokenDecimals as $crate::ConstGet<u8>>::VALUE as u32);
+        pub const CENTS: Balance = UNIT / 100;
+        pub const MILLICENTS: Balance = CENTS / 1_000;
+        pub const MICROCENTS: Balance = MILLICENTS / 1_000;
+        pub const EXISTENTIAL_DEPOSIT: Balance = MILLICENTS;
+
+        // Deposit to be reserved for storing `items` entries of `bytes` total size on-chain.
+        pub const fn deposit(items: u32, bytes: u32) -> Balance {
+            items as Balance * 15 * CENTS + (bytes as Balance) * 6 * CENTS
+        }
+
+        // Token properties to be passed to the chain specification builder, so that wallets and explorers
+        // display balances with the same decimals and symbol as the runtime.
+        #[cfg(feature = "std")]
+        pub fn chain_spec_properties() -> serde_json::Map<String, serde_json::Value> {
+            let mut properties = serde_json::Map::new();
+            properties.insert(
+                "tokenDecimals".into(),
+                <<$t as SystemConfig>::TokenDecimals as $crate::ConstGet<u8>>::VALUE.into(),
+            );
+            if let Some(symbol) = <<$t as SystemConfig>::TokenSymbol as frame_support::traits::Get<Option<&'static str>>>::get() {
+                properties.insert("tokenSymbol".into(), symbol.into());
+            }
+            properties.insert(
+                "ss58Format".into(),
+                <<Runtime as frame_system::Config>::SS58Prefix as frame_support::traits::Get<u16>>::get().into(),
+            );
+            properties
+        }
     };
 }
 
@@ -333,3 +460,9 @@ pub const PALLET_NAMES: [(&str, &str); 10] = [
     ("ParachainSystem", "cumulus_pallet_parachain_system"),
     ("Multisig", "pallet_multisig"),
 ];
+
+pub const MIGRATIONS_PALLET_NAMES: [(&str, &str); 1] =
+    [("MultiBlockMigrations", "pallet_migrations")];
+
+pub const DEPOSITS_PALLET_NAMES: [(&str, &str); 1] =
+    [("Deposits", "openzeppelin_pallet_abstractions::deposits")];
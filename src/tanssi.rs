@@ -1,11 +1,50 @@
+//! Implements the OpenZeppelin Tanssi configuration for a Runtime.
+//!
+//! This macro sets up the necessary configurations for the following pallets:
+//! - `pallet_author_inherent`
+//! - `pallet_cc_authorities_noting`
+//!
+//! Pass the `MaintenanceMode` mode to also configure `pallet_maintenance_mode`. Its pallet is listed
+//! separately in [`MAINTENANCE_PALLET_NAMES`] (`MaintenanceMode` in `construct_openzeppelin_runtime`), so that
+//! adding it does not shift the indices of the pallets of existing runtimes.
+//!
+//! # Parameters
+//! - `$t`: A type that implements the `TanssiConfig` trait, providing the necessary associated types
+//!   and configurations.
+//!
+//! # Important
+//! Maintenance mode only filters calls if it is passed to `SystemConfig::CallFilter`
+//! (`type CallFilter = MaintenanceMode;`). While it is enabled, only the calls of `System`, `Timestamp`,
+//! `ParachainSystem`, `Preimage`, `Utility`, `AuthorInherent`, `AuthoritiesNoting` and `MaintenanceMode` are
+//! allowed, plus the calls in `TanssiConfig::MaintenanceAllowedCalls`. Calls used to reach
+//! `TanssiConfig::MaintenanceOrigin`, e.g. `Sudo` or the governance pallets, must be added there, otherwise
+//! the chain can not resume from maintenance mode. `TanssiConfig::MaintenanceOrigin` defaults to Root
+//! ([`EnsureRootOrigin`]).
+//!
+//! The macro generates `FindAuthorAccount`, which maps the `NimbusId` of the block author to its account
+//! through `TanssiConfig::AccountLookup`. For the EVM grouping, pass [`FindAuthorH160`] as
+//! `EvmConfig::FindAuthor`, e.g. `FindAuthorH160<FindAuthorAccount, AccountId>`.
+
+use core::marker::PhantomData;
+
+use frame_support::{
+    dispatch::RawOrigin,
+    traits::{EnsureOrigin, FindAuthor, OriginTrait},
+};
+use sp_core::H160;
+use sp_runtime::ConsensusEngineId;
+
 #[macro_export]
 macro_rules! impl_openzeppelin_tanssi {
-    ($t:ty) => {
+    (@common $t:ty) => {
         impl pallet_author_inherent::Config for Runtime {
             type AuthorId = nimbus_primitives::NimbusId;
-            type AccountLookup = dp_consensus::NimbusLookUp;
-            type CanAuthor = pallet_cc_authorities_noting::CanAuthor<Runtime>;
-            type SlotBeacon = dp_consensus::AuraDigestSlotBeacon<Runtime>;
+            // Maps the author id to the account that is checked by `CanAuthor`.
+            type AccountLookup = <$t as TanssiConfig>::AccountLookup;
+            // Checks whether the author is eligible for the slot, e.g. the authorities noted from the orchestrator.
+            type CanAuthor = <$t as TanssiConfig>::CanAuthor;
+            // Source of the slot number, e.g. the Aura digest of the block.
+            type SlotBeacon = <$t as TanssiConfig>::SlotBeacon;
             type WeightInfo = <$t as TanssiWeight>::AuthorInherent;
         }
 
@@ -17,10 +56,121 @@ macro_rules! impl_openzeppelin_tanssi {
             type AuthorityId = nimbus_primitives::NimbusId;
             type WeightInfo = <$t as TanssiWeight>::AuthoritiesNoting;
         }
+
+        // Finds the account of the block author by mapping its `NimbusId` through `AccountLookup`.
+        pub struct FindAuthorAccount;
+        impl frame_support::traits::FindAuthor<AccountId> for FindAuthorAccount {
+            fn find_author<'a, I>(digests: I) -> Option<AccountId>
+            where
+                I: 'a + IntoIterator<Item = (sp_runtime::ConsensusEngineId, &'a [u8])>,
+            {
+                let author = <pallet_author_inherent::Pallet<Runtime> as frame_support::traits::FindAuthor<
+                    nimbus_primitives::NimbusId,
+                >>::find_author(digests)?;
+                <<$t as TanssiConfig>::AccountLookup as nimbus_primitives::AccountLookup<AccountId>>::lookup_account(
+                    &author,
+                )
+            }
+        }
+    };
+
+    ($t:ty) => {
+        $crate::impl_openzeppelin_tanssi!(@common $t);
+    };
+    ($t:ty, MaintenanceMode) => {
+        $crate::impl_openzeppelin_tanssi!(@common $t);
+
+        // Calls allowed while the container chain is in maintenance mode: block production, runtime upgrades,
+        // maintenance mode itself and the calls in `TanssiConfig::MaintenanceAllowedCalls`.
+        pub struct MaintenanceFilter;
+        impl frame_support::traits::Contains<RuntimeCall> for MaintenanceFilter {
+            fn contains(c: &RuntimeCall) -> bool {
+                matches!(
+                    c,
+                    RuntimeCall::System(_)
+                        | RuntimeCall::Timestamp(_)
+                        | RuntimeCall::ParachainSystem(_)
+                        | RuntimeCall::Preimage(_)
+                        | RuntimeCall::Utility(_)
+                        | RuntimeCall::AuthorInherent(_)
+                        | RuntimeCall::AuthoritiesNoting(_)
+                        | RuntimeCall::MaintenanceMode(_)
+                ) || <<$t as TanssiConfig>::MaintenanceAllowedCalls as frame_support::traits::Contains<RuntimeCall>>::contains(c)
+            }
+        }
+
+        impl pallet_maintenance_mode::Config for Runtime {
+            type RuntimeEvent = RuntimeEvent;
+            // `NormalFilter` of the System abstraction already consults this pallet through
+            // `SystemConfig::CallFilter`, so nothing else is filtered in normal operation.
+            type NormalCallFilter = frame_support::traits::Everything;
+            type MaintenanceCallFilter = MaintenanceFilter;
+            // Origin that can enter and resume from maintenance mode.
+            type MaintenanceOrigin = <$t as TanssiConfig>::MaintenanceOrigin;
+            type XcmExecutionManager = <$t as TanssiConfig>::XcmExecutionManager;
+        }
+    };
+    ($t:ty, $mode:ident) => {
+        compile_error!(concat!(
+            "Unknown Tanssi mode `",
+            stringify!($mode),
+            "`. Available modes are MaintenanceMode."
+        ));
     };
 }
 
+/// Root origin of any runtime, the default `TanssiConfig::MaintenanceOrigin`. Unlike
+/// `frame_system::EnsureRoot`, it does not need the `AccountId` of the runtime.
+pub struct EnsureRootOrigin;
+impl<O: OriginTrait> EnsureOrigin<O> for EnsureRootOrigin {
+    type Success = ();
+
+    fn try_origin(o: O) -> Result<(), O> {
+        match o.as_system_ref() {
+            Some(RawOrigin::Root) => Ok(()),
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Ok(O::root())
+    }
+}
+
+/// Converts the account of the author found by `Inner`, e.g. `FindAuthorAccount`, into an `H160`.
+pub struct FindAuthorH160<Inner, AccountId>(PhantomData<(Inner, AccountId)>);
+impl<Inner, AccountId> FindAuthor<H160> for FindAuthorH160<Inner, AccountId>
+where
+    Inner: FindAuthor<AccountId>,
+    AccountId: Into<H160>,
+{
+    fn find_author<'a, I>(digests: I) -> Option<H160>
+    where
+        I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+    {
+        Inner::find_author(digests).map(Into::into)
+    }
+}
+
+sp_api::decl_runtime_apis! {
+    /// Queries of the authorities assigned to the container chain by the orchestrator, as noted by
+    /// `pallet_cc_authorities_noting`, for collators and node operators.
+    pub trait TanssiAuthoritiesApi<AuthorityId>
+    where
+        AuthorityId: parity_scale_codec::Codec,
+    {
+        /// Authorities noted in the current block, in the order authoring slots are assigned to them.
+        fn authorities() -> sp_std::vec::Vec<AuthorityId>;
+        /// Whether `author` is one of the noted authorities.
+        fn is_authority(author: AuthorityId) -> bool;
+    }
+}
+
 pub const PALLET_NAMES: [(&str, &str); 2] = [
     ("AuthorInherent", "pallet_author_inherent"),
     ("AuthoritiesNoting", "pallet_cc_authorities_noting"),
 ];
+
+pub const MAINTENANCE_PALLET_NAMES: [(&str, &str); 1] =
+    [("MaintenanceMode", "pallet_maintenance_mode")];
@@ -0,0 +1,138 @@
+//! Implements the OpenZeppelin vesting configuration for a Runtime.
+//!
+//! This macro sets up the necessary configurations for the following pallets:
+//! - `pallet_vesting`
+//!
+//! # Parameters
+//! - `$t`: A type that implements the `VestingConfig` trait, providing the necessary associated types
+//!   and configurations.
+//!
+//! Schedules of a token launch are usually set at genesis. [`genesis_vesting`] builds the `vesting` field of
+//! the `pallet_vesting` genesis config for a genesis preset. Schedules are exposed to wallets by
+//! [`VestingApi`], and the `Vesting` grouping of `impl_openzeppelin_proxy_type!` lets a proxy unlock vested
+//! funds without being able to transfer them.
+//!
+//! # Important
+//! Rerun benchmarks if making changes to runtime configuration, as weight calculations
+//! may need to be updated.
+
+use sp_runtime::{traits::AtLeast32BitUnsigned, Perbill};
+use sp_std::prelude::Vec;
+
+#[macro_export]
+macro_rules! impl_openzeppelin_vesting {
+    ($t:ty) => {
+        impl pallet_vesting::Config for Runtime {
+            // Converts a block number into a balance, to compute the amount unlocked every block.
+            type BlockNumberToBalance = sp_runtime::traits::ConvertInto;
+            // The currency locked by vesting.
+            type Currency = Balances;
+            // The minimum amount transferred to call `vested_transfer`.
+            type MinVestedTransfer = <$t as VestingConfig>::MinVestedTransfer;
+            // The overarching event type.
+            type RuntimeEvent = RuntimeEvent;
+            // Reasons for which the funds that are still vesting can be withdrawn.
+            type UnvestedFundsAllowedWithdrawReasons =
+                <$t as VestingConfig>::UnvestedFundsAllowedWithdrawReasons;
+            type WeightInfo = <$t as VestingWeight>::Vesting;
+
+            // The maximum number of vesting schedules of an account.
+            const MAX_VESTING_SCHEDULES: u32 =
+                <<$t as VestingConfig>::MaxVestingSchedules as $crate::ConstGet<u32>>::VALUE;
+        }
+    };
+}
+
+/// Vesting schedule of an account, as returned by [`VestingApi`].
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+    parity_scale_codec::Encode,
+    parity_scale_codec::Decode,
+    scale_info::TypeInfo,
+)]
+pub struct VestingSchedule<Balance, BlockNumber> {
+    /// Amount locked at the start of the schedule.
+    pub locked: Balance,
+    /// Amount unlocked every block after `starting_block`.
+    pub per_block: Balance,
+    /// Block from which the funds start to unlock.
+    pub starting_block: BlockNumber,
+}
+
+/// Builds the `vesting` field of the `pallet_vesting` genesis config, in which each schedule is
+/// `(who, begin, length, liquid)`. `liquid` of every allocation is available from genesis and the rest unlocks
+/// linearly over `length` blocks starting at `begin`.
+///
+/// The allocations must also be endowed in the `pallet_balances` genesis config, as the schedules only lock
+/// part of the genesis balance of each account.
+pub fn genesis_vesting<AccountId, Balance, BlockNumber>(
+    allocations: impl IntoIterator<Item = (AccountId, Balance)>,
+    begin: BlockNumber,
+    length: BlockNumber,
+    liquid: Perbill,
+) -> Vec<(AccountId, BlockNumber, BlockNumber, Balance)>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+    BlockNumber: Copy,
+{
+    allocations
+        .into_iter()
+        .map(|(who, amount)| (who, begin, length, liquid.mul_floor(amount)))
+        .collect()
+}
+
+sp_api::decl_runtime_apis! {
+    /// Vesting schedules of accounts, for wallets.
+    pub trait VestingApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: parity_scale_codec::Codec,
+        Balance: parity_scale_codec::Codec,
+        BlockNumber: parity_scale_codec::Codec,
+    {
+        /// Vesting schedules of `who`.
+        fn vesting_schedules(who: AccountId) -> Vec<VestingSchedule<Balance, BlockNumber>>;
+        /// Balance of `who` that is still locked by vesting, `None` if `who` has no vesting schedule.
+        fn vesting_balance(who: AccountId) -> Option<Balance>;
+    }
+}
+
+pub const PALLET_NAMES: [(&str, &str); 1] = [("Vesting", "pallet_vesting")];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn genesis_vesting_keeps_the_liquid_share_of_every_allocation() {
+        let schedules = genesis_vesting(
+            [(1u64, 1_000u128), (2, 5_000)],
+            10u32,
+            100,
+            Perbill::from_percent(10),
+        );
+        assert_eq!(schedules, vec![(1, 10, 100, 100), (2, 10, 100, 500)]);
+    }
+
+    #[test]
+    fn genesis_vesting_rounds_the_liquid_share_down() {
+        let schedules = genesis_vesting([(1u64, 999u128)], 0u32, 100, Perbill::from_percent(10));
+        assert_eq!(schedules, vec![(1, 0, 100, 99)]);
+    }
+
+    #[test]
+    fn genesis_vesting_bounds() {
+        let allocations = [(1u64, 1_000u128)];
+        assert_eq!(
+            genesis_vesting(allocations, 0u32, 100, Perbill::zero()),
+            vec![(1, 0, 100, 0)]
+        );
+        assert_eq!(
+            genesis_vesting(allocations, 0u32, 100, Perbill::one()),
+            vec![(1, 0, 100, 1_000)]
+        );
+        assert!(genesis_vesting(Vec::<(u64, u128)>::new(), 0u32, 100, Perbill::one()).is_empty());
+    }
+}
@@ -9,11 +9,13 @@ pub trait SystemWeight {
     type ParachainSystem = ();
     type Balances = ();
     type Utility = ();
+    type Migrations = ();
     type DbWeight;
 }
 
 pub trait ConsensusWeight {
     type CollatorSelection = ();
+    type CollatorStaking = ();
     type Session = ();
 }
 
@@ -22,6 +24,11 @@ pub trait AssetsWeight {
     type AssetManager = ();
     type OrmlOracle = ();
     type OracleMembership = ();
+    type PoolAssets = ();
+    type AssetConversion = ();
+    type ForeignAssets = ();
+    // Hand-written estimate, replace it with the weights generated by the benchmark of the pallet
+    type AssetFeeAccounting = ();
 }
 
 pub trait GovernanceWeight {
@@ -44,6 +51,14 @@ pub trait EvmWeight {
     type Evm = ();
 }
 
+pub trait NftsWeight {
+    type Nfts = ();
+}
+
+pub trait VestingWeight {
+    type Vesting = ();
+}
+
 pub trait TanssiWeight {
     type AuthorInherent = ();
     type AuthoritiesNoting = ();
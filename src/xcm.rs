@@ -17,6 +17,15 @@
 //! Rerun benchmarks if making changes to runtime configuration, as weight calculations
 //! may need to be updated.
 
+use ::xcm::latest::Location;
+use core::marker::PhantomData;
+use frame_support::{
+    dispatch::DispatchClass,
+    traits::{Contains, Get},
+    weights::Weight,
+};
+use sp_runtime::{traits::Convert, DispatchResult};
+
 #[macro_export]
 macro_rules! impl_openzeppelin_xcm {
     ($t:ty) => {
@@ -250,6 +259,55 @@ macro_rules! impl_openzeppelin_xcm {
             type XcmExecutor = XcmExecutor<XcmExecutorConfig>;
         }
 
+        /// Access of [`WeightTraderAssetRegistration`] to `pallet_xcm_weight_trader`. The calls of the pallet
+        /// are dispatched with the Root origin, see `openzeppelin_pallet_abstractions::xcm::WeightTraderRegistration`
+        /// for the assets they are made for.
+        pub struct PalletXcmWeightTraderAssets;
+        impl $crate::xcm::SupportedAssets for PalletXcmWeightTraderAssets {
+            type Runtime = Runtime;
+
+            fn contains(location: &Location) -> bool {
+                pallet_xcm_weight_trader::SupportedAssets::<Runtime>::contains_key(location)
+            }
+
+            fn add_paused(location: Location, relative_price: u128) -> sp_runtime::DispatchResult {
+                pallet_xcm_weight_trader::Pallet::<Runtime>::add_asset(
+                    frame_system::RawOrigin::Root.into(),
+                    location.clone(),
+                    relative_price,
+                )?;
+                pallet_xcm_weight_trader::Pallet::<Runtime>::pause_asset_support(
+                    frame_system::RawOrigin::Root.into(),
+                    location,
+                )
+            }
+
+            fn remove(location: Location) -> sp_runtime::DispatchResult {
+                pallet_xcm_weight_trader::Pallet::<Runtime>::remove_asset(
+                    frame_system::RawOrigin::Root.into(),
+                    location,
+                )
+            }
+
+            fn add_paused_weight() -> frame_support::weights::Weight {
+                use pallet_xcm_weight_trader::WeightInfo;
+
+                <Runtime as pallet_xcm_weight_trader::Config>::WeightInfo::add_asset()
+                    .saturating_add(<Runtime as pallet_xcm_weight_trader::Config>::WeightInfo::pause_asset_support())
+            }
+
+            fn remove_weight() -> frame_support::weights::Weight {
+                use pallet_xcm_weight_trader::WeightInfo;
+
+                <Runtime as pallet_xcm_weight_trader::Config>::WeightInfo::remove_asset()
+            }
+        }
+
+        /// Asset lifecycle hook adding the assets created by `TrustedCreators` to `pallet_xcm_weight_trader`,
+        /// see `openzeppelin_pallet_abstractions::xcm::WeightTraderRegistration`.
+        pub type WeightTraderAssetRegistration<AssetLocation, RelativePrice, TrustedCreators> =
+            $crate::xcm::WeightTraderRegistration<PalletXcmWeightTraderAssets, AssetLocation, RelativePrice, TrustedCreators>;
+
         // A pallet to trade weight for XCM execution costs.
         impl pallet_xcm_weight_trader::Config for Runtime {
             // Conversion logic from AccountId to XCM Location.
@@ -338,3 +396,283 @@ pub const PALLET_NAMES: [(&str, &str); 7] = [
     ("XTokens", "orml_xtokens"),
     ("XcmTransactor", "pallet_xcm_transactor"),
 ];
+
+/// Assets XCM fees can be paid in, updated by [`WeightTraderRegistration`]. Implemented for
+/// `pallet_xcm_weight_trader` by the `PalletXcmWeightTraderAssets` type generated by [`impl_openzeppelin_xcm!`].
+pub trait SupportedAssets {
+    /// Runtime the weight of the updates is registered in.
+    type Runtime: frame_system::Config;
+
+    fn contains(location: &Location) -> bool;
+    /// Adds `location` at `relative_price`, paused so that fees are not accepted in it yet.
+    fn add_paused(location: Location, relative_price: u128) -> DispatchResult;
+    fn remove(location: Location) -> DispatchResult;
+    fn add_paused_weight() -> Weight;
+    fn remove_weight() -> Weight;
+}
+
+/// Asset lifecycle hook adding the assets created by `TrustedCreators` to `Assets` at `RelativePrice`. They
+/// are added paused, so XCM fees are only accepted in them once `XcmConfig::EditSupportedAssetOrigin` set
+/// their price and `XcmConfig::ResumeSupportedAssetOrigin` resumed them. Destroyed assets are removed if
+/// they are supported.
+///
+/// `pallet_xcm_weight_trader` is updated with the Root origin, bypassing `XcmConfig::AddSupportedAssetOrigin`,
+/// so the creators must be trusted to list assets: use `Everything` only when creating assets is itself
+/// privileged, e.g. the `ForeignAssets` instance without `Permissionless`, where assets are only created
+/// with `force_create`. Assets of other creators are left to `XcmConfig::AddSupportedAssetOrigin`. The Root
+/// origin is only used for the location of the created or destroyed asset, given by `AssetLocation`.
+///
+/// The weight of the updates is registered as extra weight of the block and failures are logged.
+pub struct WeightTraderRegistration<Assets, AssetLocation, RelativePrice, TrustedCreators>(
+    PhantomData<(Assets, AssetLocation, RelativePrice, TrustedCreators)>,
+);
+impl<AssetId, AccountId, Assets, AssetLocation, RelativePrice, TrustedCreators>
+    crate::assets::OnAssetLifecycle<AssetId, AccountId>
+    for WeightTraderRegistration<Assets, AssetLocation, RelativePrice, TrustedCreators>
+where
+    AssetId: Clone,
+    Assets: SupportedAssets,
+    AssetLocation: Convert<AssetId, Option<Location>>,
+    RelativePrice: Get<u128>,
+    TrustedCreators: Contains<AccountId>,
+{
+    fn on_created(id: &AssetId, owner: &AccountId) {
+        if !TrustedCreators::contains(owner) {
+            return;
+        }
+        let Some(location) = AssetLocation::convert(id.clone()) else {
+            return;
+        };
+        frame_system::Pallet::<Assets::Runtime>::register_extra_weight_unchecked(
+            Assets::add_paused_weight(),
+            DispatchClass::Normal,
+        );
+        if let Err(error) = Assets::add_paused(location.clone(), RelativePrice::get()) {
+            log::warn!(
+                target: "runtime::xcm",
+                "Created asset {:?} could not be added to the weight trader: {:?}",
+                location,
+                error,
+            );
+        }
+    }
+
+    fn on_destroyed(id: &AssetId) {
+        let Some(location) = AssetLocation::convert(id.clone()) else {
+            return;
+        };
+        let read = <Assets::Runtime as frame_system::Config>::DbWeight::get().reads(1);
+        frame_system::Pallet::<Assets::Runtime>::register_extra_weight_unchecked(
+            read,
+            DispatchClass::Normal,
+        );
+        if !Assets::contains(&location) {
+            return;
+        }
+        frame_system::Pallet::<Assets::Runtime>::register_extra_weight_unchecked(
+            Assets::remove_weight(),
+            DispatchClass::Normal,
+        );
+        if let Err(error) = Assets::remove(location.clone()) {
+            log::warn!(
+                target: "runtime::xcm",
+                "Destroyed asset {:?} could not be removed from the weight trader: {:?}",
+                location,
+                error,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::BTreeMap};
+
+    use ::xcm::latest::Junction;
+    use frame_support::{
+        derive_impl, parameter_types,
+        traits::{ConstU128, Everything},
+        weights::constants::RocksDbWeight,
+    };
+    use sp_runtime::{BuildStorage, DispatchError};
+
+    use super::*;
+    use crate::{assets::OnAssetLifecycle, test_log};
+
+    type Block = frame_system::mocking::MockBlock<Test>;
+
+    frame_support::construct_runtime!(
+        pub enum Test {
+            System: frame_system,
+        }
+    );
+
+    #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+    impl frame_system::Config for Test {
+        type Block = Block;
+        type DbWeight = RocksDbWeight;
+    }
+
+    const TRUSTED: u64 = 1;
+    const UNTRUSTED: u64 = 2;
+
+    thread_local! {
+        // Relative price and paused status of the supported assets.
+        static SUPPORTED: RefCell<BTreeMap<Location, (u128, bool)>> = const { RefCell::new(BTreeMap::new()) };
+    }
+
+    parameter_types! {
+        pub static Failing: bool = false;
+    }
+
+    struct WeightTrader;
+    impl SupportedAssets for WeightTrader {
+        type Runtime = Test;
+
+        fn contains(location: &Location) -> bool {
+            SUPPORTED.with(|assets| assets.borrow().contains_key(location))
+        }
+
+        fn add_paused(location: Location, relative_price: u128) -> DispatchResult {
+            if Failing::get() {
+                return Err(DispatchError::Other("failing"));
+            }
+            SUPPORTED.with(|assets| assets.borrow_mut().insert(location, (relative_price, true)));
+            Ok(())
+        }
+
+        fn remove(location: Location) -> DispatchResult {
+            if Failing::get() {
+                return Err(DispatchError::Other("failing"));
+            }
+            SUPPORTED.with(|assets| assets.borrow_mut().remove(&location));
+            Ok(())
+        }
+
+        fn add_paused_weight() -> Weight {
+            Weight::from_parts(100, 0)
+        }
+
+        fn remove_weight() -> Weight {
+            Weight::from_parts(10, 0)
+        }
+    }
+
+    // Asset 0 has no location.
+    struct AssetLocation;
+    impl Convert<u32, Option<Location>> for AssetLocation {
+        fn convert(id: u32) -> Option<Location> {
+            (id != 0).then(|| Junction::GeneralIndex(id.into()).into())
+        }
+    }
+
+    struct Trusted;
+    impl Contains<u64> for Trusted {
+        fn contains(account: &u64) -> bool {
+            *account == TRUSTED
+        }
+    }
+
+    type Registration =
+        WeightTraderRegistration<WeightTrader, AssetLocation, ConstU128<7>, Trusted>;
+    type OpenRegistration =
+        WeightTraderRegistration<WeightTrader, AssetLocation, ConstU128<7>, Everything>;
+
+    fn create<Hook: OnAssetLifecycle<u32, u64>>(id: u32, owner: u64) {
+        Hook::on_created(&id, &owner);
+    }
+
+    fn destroy<Hook: OnAssetLifecycle<u32, u64>>(id: u32) {
+        Hook::on_destroyed(&id);
+    }
+
+    fn new_test_ext() -> sp_io::TestExternalities {
+        frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap()
+            .into()
+    }
+
+    fn supported() -> Vec<(Location, (u128, bool))> {
+        SUPPORTED.with(|assets| assets.borrow().clone().into_iter().collect())
+    }
+
+    fn location(id: u32) -> Location {
+        AssetLocation::convert(id).unwrap()
+    }
+
+    fn extra_weight() -> Weight {
+        *frame_system::BlockWeight::<Test>::get().get(DispatchClass::Normal)
+    }
+
+    #[test]
+    fn created_assets_of_trusted_creators_are_added_paused() {
+        new_test_ext().execute_with(|| {
+            create::<Registration>(1, TRUSTED);
+            assert_eq!(supported(), vec![(location(1), (7, true))]);
+            assert_eq!(extra_weight(), Weight::from_parts(100, 0));
+        });
+    }
+
+    #[test]
+    fn created_assets_of_other_creators_are_not_added() {
+        new_test_ext().execute_with(|| {
+            create::<Registration>(1, UNTRUSTED);
+            assert!(supported().is_empty());
+            assert_eq!(extra_weight(), Weight::zero());
+
+            create::<OpenRegistration>(1, UNTRUSTED);
+            assert_eq!(supported(), vec![(location(1), (7, true))]);
+        });
+    }
+
+    #[test]
+    fn assets_without_location_are_ignored() {
+        new_test_ext().execute_with(|| {
+            create::<Registration>(0, TRUSTED);
+            destroy::<Registration>(0);
+            assert!(supported().is_empty());
+            assert_eq!(extra_weight(), Weight::zero());
+        });
+    }
+
+    #[test]
+    fn destroyed_assets_are_removed() {
+        new_test_ext().execute_with(|| {
+            create::<Registration>(1, TRUSTED);
+            create::<Registration>(2, TRUSTED);
+            destroy::<Registration>(1);
+            assert_eq!(supported(), vec![(location(2), (7, true))]);
+            assert_eq!(
+                extra_weight(),
+                Weight::from_parts(210, 0).saturating_add(RocksDbWeight::get().reads(1))
+            );
+        });
+    }
+
+    #[test]
+    fn destroyed_assets_that_are_not_supported_are_skipped() {
+        new_test_ext().execute_with(|| {
+            Failing::set(true);
+            let logs = test_log::capture(|| destroy::<Registration>(1));
+            assert!(logs.is_empty());
+            assert_eq!(extra_weight(), RocksDbWeight::get().reads(1));
+        });
+    }
+
+    #[test]
+    fn failures_are_logged() {
+        new_test_ext().execute_with(|| {
+            create::<Registration>(1, TRUSTED);
+            Failing::set(true);
+            let logs = test_log::capture(|| {
+                create::<Registration>(2, TRUSTED);
+                destroy::<Registration>(1);
+            });
+            assert_eq!(logs.len(), 2);
+            assert!(logs[0].starts_with("runtime::xcm: Created asset"));
+            assert!(logs[1].starts_with("runtime::xcm: Destroyed asset"));
+            assert_eq!(supported(), vec![(location(1), (7, true))]);
+        });
+    }
+}